@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use possible_fuzz::{assert_round_trip, Record};
+
+fuzz_target!(|record: Record| {
+    let serialized = serde_yaml::to_string(&record).expect("Failed to serialize record");
+    let decoded: Record = serde_yaml::from_str(&serialized).expect("Failed to deserialize record");
+
+    assert_round_trip(&record, &decoded, &serialized);
+});
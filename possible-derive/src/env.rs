@@ -0,0 +1,134 @@
+use crate::util::possible_inner;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{Data, DeriveInput, Error, Fields, LitStr, Result};
+
+#[derive(Default)]
+struct Container {
+    prefix: String,
+}
+
+#[derive(Default)]
+struct FieldOptions {
+    rename: Option<String>,
+}
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let container = parse_container(&input)?;
+
+    let named = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "`FromEnv` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`FromEnv` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut reads = Vec::new();
+    let mut assignments = Vec::new();
+    for field in named {
+        let options = parse_field(field)?;
+        if possible_inner(&field.ty).is_none() {
+            return Err(Error::new_spanned(
+                &field.ty,
+                "`FromEnv` fields must be declared as `Possible<T>`",
+            ));
+        }
+
+        // named fields always have an identifier
+        let ident = field.ident.as_ref().unwrap();
+        let name = options
+            .rename
+            .unwrap_or_else(|| ident.unraw().to_string().to_uppercase());
+        let name = format!("{}{}", container.prefix, name);
+
+        // prefixed to not collide with the locals of the generated function
+        let local = format_ident!("__field_{}", ident.unraw());
+
+        reads.push(quote! {
+            let #local = ::possible::Possible::from_env_source(__source, #name)
+                .unwrap_or_else(|error| {
+                    __errors.push(error);
+                    ::possible::Possible::Void
+                });
+        });
+        assignments.push(quote!(#ident: #local));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::possible::env::FromEnv for #ident #ty_generics #where_clause {
+            fn from_env_source<S: ::possible::env::Source + ?Sized>(
+                __source: &S,
+            ) -> ::core::result::Result<Self, ::possible::env::EnvErrors> {
+                let mut __errors = ::possible::env::EnvErrors::new();
+                #(#reads)*
+
+                if __errors.is_empty() {
+                    ::core::result::Result::Ok(Self {
+                        #(#assignments,)*
+                    })
+                } else {
+                    ::core::result::Result::Err(__errors)
+                }
+            }
+        }
+    })
+}
+
+fn parse_container(input: &DeriveInput) -> Result<Container> {
+    let mut container = Container::default();
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("env"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("prefix") {
+                let prefix: LitStr = meta.value()?.parse()?;
+                container.prefix = prefix.value();
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `env` attribute, expected `prefix`"))
+            }
+        })?;
+    }
+
+    Ok(container)
+}
+
+fn parse_field(field: &syn::Field) -> Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("env"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                let rename: LitStr = meta.value()?.parse()?;
+                options.rename = Some(rename.value());
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `env` attribute, expected `rename`"))
+            }
+        })?;
+    }
+
+    Ok(options)
+}
@@ -0,0 +1,91 @@
+use crate::util::possible_inner;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{meta::ParseNestedMeta, parse_quote, Error, Fields, ItemStruct, Result, Token};
+
+/// Serde field attributes that conflict with the ones added to `Possible` fields.
+const CONFLICTS: &[&str] = &[
+    "default",
+    "skip_serializing_if",
+    "skip",
+    "skip_serializing",
+    "skip_deserializing",
+    "flatten",
+];
+
+pub fn expand(mut input: ItemStruct) -> Result<TokenStream> {
+    let named = match &mut input.fields {
+        Fields::Named(named) => &mut named.named,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`possible::fields` can only be used on structs with named fields",
+            ))
+        }
+    };
+
+    let mut errors = Vec::new();
+
+    for field in named.iter_mut() {
+        if possible_inner(&field.ty).is_none() {
+            continue;
+        }
+
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("serde"))
+        {
+            let result = attr.parse_nested_meta(|meta| {
+                if let Some(name) = CONFLICTS.iter().find(|name| meta.path.is_ident(name)) {
+                    return Err(meta.error(format_args!(
+                        "`serde({})` conflicts with the attributes added by `possible::fields` \
+                         to `Possible` fields",
+                        name
+                    )));
+                }
+
+                skip_meta(&meta)
+            });
+
+            if let Err(error) = result {
+                errors.push(error);
+            }
+        }
+
+        field.attrs.push(parse_quote! {
+            #[serde(default, skip_serializing_if = "::possible::Possible::is_void")]
+        });
+    }
+
+    if let Some(error) = errors.into_iter().reduce(|mut all, error| {
+        all.combine(error);
+        all
+    }) {
+        return Err(error);
+    }
+
+    Ok(input.into_token_stream())
+}
+
+/// Consumes the value of a serde attribute that is not inspected.
+fn skip_meta(meta: &ParseNestedMeta) -> Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|nested| skip_meta(&nested))?;
+    }
+
+    Ok(())
+}
+
+pub fn check_arguments(arguments: TokenStream) -> Result<()> {
+    if arguments.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::new_spanned(
+            arguments,
+            "`possible::fields` does not take any arguments",
+        ))
+    }
+}
@@ -0,0 +1,244 @@
+use crate::util::possible_inner;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{
+    punctuated::Punctuated, Data, DeriveInput, Error, Fields, Ident, LitStr, Path, Result, Token,
+    Type, Visibility,
+};
+
+struct Container {
+    name: Option<Ident>,
+    derives: Vec<Path>,
+}
+
+struct Field<'a> {
+    ident: &'a Ident,
+    vis: &'a Visibility,
+    /// The `T` of the field declared as `Possible<T>`.
+    ty: &'a Type,
+    options: FieldOptions,
+}
+
+#[derive(Default)]
+struct FieldOptions {
+    required: bool,
+    nested: bool,
+}
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let container = parse_container(&input)?;
+
+    let named = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "`Layered` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`Layered` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut fields = Vec::new();
+    for field in named {
+        let options = parse_field(field)?;
+        let ty = possible_inner(&field.ty).ok_or_else(|| {
+            Error::new_spanned(
+                &field.ty,
+                "`Layered` fields must be declared as `Possible<T>`",
+            )
+        })?;
+
+        fields.push(Field {
+            // named fields always have an identifier
+            ident: field.ident.as_ref().unwrap(),
+            vis: &field.vis,
+            ty,
+            options,
+        });
+    }
+
+    let layer = &input.ident;
+    let vis = &input.vis;
+    let name = container
+        .name
+        .unwrap_or_else(|| format_ident!("{}Resolved", layer));
+    let derives = &container.derives;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let generics = &input.generics;
+
+    let doc = format!(
+        "Configuration resolved from a stack of [`{}`] layers.",
+        layer
+    );
+
+    let declarations = fields.iter().map(|field| {
+        let Field { ident, vis, ty, .. } = field;
+        let ty = if field.options.nested {
+            quote!(<#ty as ::possible::layer::Layered>::Output)
+        } else if field.options.required {
+            quote!(#ty)
+        } else {
+            quote!(::core::option::Option<#ty>)
+        };
+
+        quote!(#vis #ident: #ty)
+    });
+
+    let merges = fields.iter().map(|field| {
+        let ident = field.ident;
+
+        if field.options.nested {
+            quote!(#ident: ::possible::layer::merge_nested(self.#ident, newer.#ident))
+        } else {
+            quote!(#ident: self.#ident.merge(newer.#ident))
+        }
+    });
+
+    let records = fields.iter().map(|field| {
+        let ident = field.ident;
+        let name = ident.unraw().to_string();
+        let path = quote!(::possible::__private::format!("{}{}", prefix, #name));
+
+        if field.options.nested {
+            let nested = format!("{}.", name);
+
+            quote! {
+                match &self.#ident {
+                    ::possible::Possible::Some(value) => ::possible::layer::Layered::record(
+                        value,
+                        &::possible::__private::format!("{}{}", prefix, #nested),
+                        layer,
+                        provenance,
+                    ),
+                    value => provenance.record(#path, layer, value),
+                }
+            }
+        } else {
+            quote!(provenance.record(#path, layer, &self.#ident);)
+        }
+    });
+
+    let resolutions = fields.iter().map(|field| {
+        let ident = field.ident;
+        let name = ident.unraw().to_string();
+        let path = quote!(::possible::__private::format!("{}{}", prefix, #name));
+
+        if field.options.nested {
+            quote!(#ident: ::possible::layer::nested(self.#ident, #path, provenance)?)
+        } else if field.options.required {
+            quote!(#ident: ::possible::layer::required(self.#ident, #path, provenance)?)
+        } else {
+            quote!(#ident: ::core::option::Option::from(self.#ident))
+        }
+    });
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(#(#derives),*)]
+        #vis struct #name #generics #where_clause {
+            #(#declarations,)*
+        }
+
+        impl #impl_generics ::possible::Merge for #layer #ty_generics #where_clause {
+            fn merge(self, newer: Self) -> Self {
+                Self {
+                    #(#merges,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::possible::layer::Layered for #layer #ty_generics #where_clause {
+            type Output = #name #ty_generics;
+
+            fn record(
+                &self,
+                prefix: &str,
+                layer: &str,
+                provenance: &mut ::possible::layer::Provenance,
+            ) {
+                #(#records)*
+            }
+
+            fn resolve(
+                self,
+                prefix: &str,
+                provenance: &::possible::layer::Provenance,
+            ) -> ::core::result::Result<Self::Output, ::possible::layer::LayerError> {
+                ::core::result::Result::Ok(#name {
+                    #(#resolutions,)*
+                })
+            }
+        }
+    })
+}
+
+fn parse_container(input: &DeriveInput) -> Result<Container> {
+    let mut container = Container {
+        name: None,
+        derives: Vec::new(),
+    };
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("layered"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                let name: LitStr = meta.value()?.parse()?;
+                container.name = Some(name.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("derive") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let paths = Punctuated::<Path, Token![,]>::parse_terminated(&content)?;
+                container.derives.extend(paths);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `layered` attribute, expected `name` or `derive`"))
+            }
+        })?;
+    }
+
+    Ok(container)
+}
+
+fn parse_field(field: &syn::Field) -> Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("layered"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("required") {
+                options.required = true;
+                Ok(())
+            } else if meta.path.is_ident("nested") {
+                options.nested = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `layered` attribute, expected `required` or `nested`"))
+            }
+        })?;
+    }
+
+    if options.required && options.nested {
+        return Err(Error::new_spanned(
+            &field.ident,
+            "`layered(nested)` fields are always required",
+        ));
+    }
+
+    Ok(options)
+}
@@ -0,0 +1,434 @@
+//! Derive macros for the [`possible`](https://docs.rs/possible) crate.
+//!
+//! These macros are re-exported by `possible` when its `derive` feature is enabled,
+//! and should be used through that crate rather than depending on this one directly.
+
+extern crate proc_macro;
+
+mod env;
+mod fields;
+mod layered;
+mod patch;
+mod sql;
+mod util;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, ItemStruct};
+
+/// Generates a `{Name}Patch` struct for partially updating a struct with named fields.
+///
+/// Every field of the patch struct is a `Possible` of the original field type, where an
+/// `Option<T>` field becomes a `Possible<T>`. The patch struct implements `Default`,
+/// `Serialize`, and `Deserialize`, with missing fields parsed as `Possible::Void` and
+/// `Possible::Void` fields omitted when serializing.
+///
+/// The patch struct implements `possible::Patch` for the original struct, applying each
+/// field with the same meaning as `Possible` itself: fields are left alone when they are
+/// `Possible::Void`, `Option` fields are cleared when they are `Possible::None`, and
+/// fields are overwritten when they are `Possible::Some`. Applying `Possible::None` to a
+/// non-`Option` field returns a `PatchError` naming the field, and every field is checked
+/// before any is applied so that the original struct is unchanged on error. The patch
+/// struct also implements `possible::Merge` to combine two patches field by field, and the
+/// original struct implements `possible::Patchable` so that it can be patched as a nested
+/// field.
+///
+/// # Attributes
+///
+/// - `#[patch(name = "FooUpdate")]` on the struct renames the generated patch struct.
+/// - `#[patch(derive(Debug, PartialEq))]` on the struct adds derives to the patch struct.
+/// - `#[patch(active_model = "ActiveModel")]` on the struct also implements `possible::Patch`
+///   for a SeaORM `ActiveModel` with the same fields, with the `sea-orm` feature enabled.
+///   `Possible::Void` fields are left as they are, while other fields are `Set`.
+/// - `#[patch(skip)]` on a field leaves that field out of the patch struct.
+/// - `#[patch(nested)]` on a field patches it recursively with the `Patchable::Patch` of its
+///   type, such as the patch struct of another `#[derive(Patch)]` struct or a map of
+///   `Possible` values. A nested `Option` field is created from its default value when a
+///   patch is applied to `None`.
+///
+/// # Examples
+///
+/// ```
+/// use possible::{Patch, Possible};
+///
+/// #[derive(Patch)]
+/// #[patch(derive(Debug, PartialEq))]
+/// struct User {
+///     #[patch(skip)]
+///     id: u64,
+///     name: String,
+///     email: Option<String>,
+/// }
+///
+/// let mut user = User {
+///     id: 7,
+///     name: String::from("Ferris"),
+///     email: Some(String::from("ferris@example.com")),
+/// };
+///
+/// let patch: UserPatch = serde_json::from_str(r#"{ "email": null }"#).unwrap();
+/// assert_eq!(patch, UserPatch {
+///     name: Possible::Void,
+///     email: Possible::None,
+/// });
+///
+/// patch.apply(&mut user).unwrap();
+/// assert_eq!(user.name, "Ferris");
+/// assert_eq!(user.email, None);
+///
+/// let patch = UserPatch {
+///     name: Possible::None,
+///     email: Possible::Void,
+/// };
+/// assert_eq!(
+///     patch.apply(&mut user).unwrap_err().to_string(),
+///     "field `name`: value cannot be null",
+/// );
+/// ```
+#[proc_macro_derive(Patch, attributes(patch))]
+pub fn derive_patch(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    patch::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `possible::layer::Layered` for a struct of `Possible` fields, which is a layer
+/// of configuration, and generates a `{Name}Resolved` struct for the resolved configuration.
+///
+/// Every field of the layer must be declared as `Possible<T>`. A `Possible<T>` field is an
+/// `Option<T>` in the resolved struct, which is `None` when no layer sets the field or the
+/// last layer that mentions it clears it. The layer also implements `possible::Merge` to
+/// combine two layers field by field, and must implement `Default` as a layer that sets
+/// nothing, such as with `#[derive(Default)]`.
+///
+/// The provenance of a field is recorded under the name of the field, or under the path
+/// through the fields of nested layers, such as `server.port`.
+///
+/// # Attributes
+///
+/// - `#[layered(name = "Config")]` on the struct renames the resolved struct.
+/// - `#[layered(derive(Debug, PartialEq))]` on the struct adds derives to the resolved
+///   struct.
+/// - `#[layered(required)]` on a field makes it a `T` in the resolved struct, which fails to
+///   resolve with a `LayerError` naming the field when it is not set or is cleared.
+/// - `#[layered(nested)]` on a field resolves it recursively as a layer of its own, such as
+///   another `#[derive(Layered)]` struct. A nested layer is merged field by field, and
+///   cannot be cleared.
+///
+/// # Examples
+///
+/// ```
+/// use possible::{
+///     layer::{LayerError, Layered, Stack},
+///     Possible,
+/// };
+///
+/// #[derive(Default, Layered)]
+/// #[layered(name = "Config", derive(Debug, PartialEq))]
+/// struct ConfigLayer {
+///     #[layered(nested)]
+///     server: Possible<ServerLayer>,
+///     proxy: Possible<String>,
+/// }
+///
+/// #[derive(Default, Layered)]
+/// #[layered(name = "Server", derive(Debug, PartialEq))]
+/// struct ServerLayer {
+///     #[layered(required)]
+///     host: Possible<String>,
+///     #[layered(required)]
+///     port: Possible<u16>,
+/// }
+///
+/// let defaults = ConfigLayer {
+///     server: Possible::Some(ServerLayer {
+///         host: Possible::Some(String::from("localhost")),
+///         port: Possible::Some(8080),
+///     }),
+///     proxy: Possible::Void,
+/// };
+/// let env = ConfigLayer {
+///     server: Possible::Some(ServerLayer {
+///         host: Possible::Void,
+///         port: Possible::Some(80),
+///     }),
+///     proxy: Possible::Some(String::from("http://proxy")),
+/// };
+///
+/// let resolved = Stack::new()
+///     .push("defaults", defaults)
+///     .push("env", env)
+///     .resolve()
+///     .unwrap();
+///
+/// assert_eq!(resolved.value, Config {
+///     server: Server { host: String::from("localhost"), port: 80 },
+///     proxy: Some(String::from("http://proxy")),
+/// });
+/// assert_eq!(resolved.provenance.get("server.host").unwrap().layer(), "defaults");
+/// assert_eq!(resolved.provenance.get("server.port").unwrap().layer(), "env");
+///
+/// let cli = ConfigLayer {
+///     server: Possible::Some(ServerLayer {
+///         host: Possible::None,
+///         port: Possible::Void,
+///     }),
+///     proxy: Possible::Void,
+/// };
+///
+/// let error = Stack::new().push("cli", cli).resolve().unwrap_err();
+/// assert_eq!(error, LayerError::Cleared(String::from("server.host"), String::from("cli")));
+/// ```
+#[proc_macro_derive(Layered, attributes(layered))]
+pub fn derive_layered(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    layered::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `possible::env::FromEnv` for a struct of `Possible` fields, which reads every
+/// field from an environment variable.
+///
+/// Every field must be declared as `Possible<T>` with a `T` that implements `FromStr`, and
+/// is read with `Possible::from_env_source`: an unset variable is `Possible::Void`, an empty
+/// variable is `Possible::None`, and any other value is parsed as `Possible::Some`. The
+/// variable of a field is named after the field in upper case, following the prefix of the
+/// struct. When variables cannot be read, the errors of every field are returned together.
+///
+/// # Attributes
+///
+/// - `#[env(prefix = "APP_")]` on the struct sets the prefix of every variable, which is
+///   empty by default.
+/// - `#[env(rename = "DATABASE_URL")]` on a field sets the name of its variable, which
+///   still follows the prefix.
+///
+/// # Examples
+///
+/// ```
+/// use possible::{env::FromEnv, Possible};
+/// use std::collections::HashMap;
+///
+/// #[derive(Debug, PartialEq, FromEnv)]
+/// #[env(prefix = "APP_")]
+/// struct Config {
+///     port: Possible<u16>,
+///     workers: Possible<u8>,
+///     #[env(rename = "DB_URL")]
+///     database_url: Possible<String>,
+/// }
+///
+/// let env = HashMap::from([("APP_PORT", "8080"), ("APP_DB_URL", "")]);
+/// assert_eq!(
+///     Config::from_env_source(&env).unwrap(),
+///     Config {
+///         port: Possible::Some(8080),
+///         workers: Possible::Void,
+///         database_url: Possible::None,
+///     },
+/// );
+///
+/// let env = HashMap::from([("APP_PORT", "http"), ("APP_WORKERS", "-1")]);
+/// let errors = Config::from_env_source(&env).unwrap_err();
+/// assert_eq!(
+///     errors.iter().map(|error| error.name()).collect::<Vec<_>>(),
+///     ["APP_PORT", "APP_WORKERS"],
+/// );
+/// ```
+#[proc_macro_derive(FromEnv, attributes(env))]
+pub fn derive_from_env(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    env::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements diesel's `AsChangeset` for a struct with named fields, where `Possible`
+/// fields that are `Possible::Void` are left out of the changeset.
+///
+/// This is a replacement for diesel's own `AsChangeset` derive, which only leaves out
+/// fields declared as `Option<T>`. A `Possible<T>` field is assigned with
+/// `possible::sql::assign`, so `Possible::None` sets the column to `NULL` and
+/// `Possible::Some` sets its value, which requires the column to be nullable. Other fields
+/// are handled the same way as by diesel, where `Option` fields are left out when they are
+/// `None`. Fields are recognized by their type being written as `Possible<T>` or
+/// `Option<T>`.
+///
+/// The changeset is only implemented for the struct itself, so it is passed to `set` by
+/// value.
+///
+/// # Attributes
+///
+/// The derive reads the same `#[diesel(...)]` attributes as diesel's derive:
+///
+/// - `#[diesel(table_name = users)]` on the struct sets the table, which is otherwise the
+///   name of the struct in snake case followed by an `s`.
+/// - `#[diesel(primary_key(id, ...))]` on the struct names the primary key columns, which
+///   are never updated and default to `id`.
+/// - `#[diesel(treat_none_as_null = true)]` on the struct sets `Option` fields to `NULL`
+///   when they are `None`.
+/// - `#[diesel(column_name = name)]` on a field sets its column.
+/// - `#[diesel(skip_update)]` on a field leaves it out of the changeset.
+///
+/// Attributes of other diesel derives, such as `check_for_backend`, are ignored so that the
+/// struct can derive other diesel traits. Unknown attributes are an error, and so are
+/// `embed`, `serialize_as`, `treat_none_as_default_value`, and `treat_none_as_null` on a
+/// field, which change how fields are written.
+///
+/// # Examples
+///
+/// ```
+/// use diesel::{debug_query, sqlite::Sqlite};
+/// use possible::Possible;
+///
+/// diesel::table! {
+///     users {
+///         id -> Integer,
+///         name -> Text,
+///         email -> Nullable<Text>,
+///         age -> Nullable<Integer>,
+///     }
+/// }
+///
+/// #[derive(possible::sql::AsChangeset)]
+/// #[diesel(table_name = users)]
+/// struct UserChanges {
+///     name: Option<String>,
+///     email: Possible<String>,
+///     age: Possible<i32>,
+/// }
+///
+/// let changes = UserChanges {
+///     name: None,
+///     email: Possible::None,
+///     age: Possible::Void,
+/// };
+///
+/// let query = diesel::update(users::table).set(changes);
+/// assert_eq!(
+///     debug_query::<Sqlite, _>(&query).to_string(),
+///     "UPDATE `users` SET `email` = ? -- binds: [None]",
+/// );
+/// ```
+#[proc_macro_derive(AsChangeset, attributes(diesel))]
+pub fn derive_as_changeset(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    sql::expand(input, sql::Derive::AsChangeset)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements diesel's `Insertable` for a struct with named fields, where `Possible` fields
+/// that are `Possible::Void` use their column default.
+///
+/// This is a replacement for diesel's own `Insertable` derive, which only uses the column
+/// default for fields declared as `Option<T>`. A `Possible<T>` field is assigned with
+/// `possible::sql::assign`, so `Possible::None` inserts `NULL` and `Possible::Some` inserts
+/// its value, which requires the column to be nullable. Other fields are handled the same
+/// way as by diesel, where `Option` fields use their column default when they are `None`.
+///
+/// The insertable is only implemented for the struct itself, so it is passed to `values`
+/// by value.
+///
+/// # Attributes
+///
+/// The derive reads the same `#[diesel(table_name = ...)]` and
+/// `#[diesel(column_name = ...)]` attributes as [`AsChangeset`](derive@AsChangeset), and
+/// `#[diesel(skip_insertion)]` on a field leaves it out of the inserted columns. Attributes
+/// of other diesel derives are ignored, and unknown or unsupported attributes are an error
+/// the same way.
+///
+/// # Examples
+///
+/// ```
+/// use diesel::{debug_query, sqlite::Sqlite};
+/// use possible::Possible;
+///
+/// diesel::table! {
+///     users {
+///         id -> Integer,
+///         name -> Text,
+///         email -> Nullable<Text>,
+///         age -> Nullable<Integer>,
+///     }
+/// }
+///
+/// #[derive(possible::sql::Insertable)]
+/// #[diesel(table_name = users)]
+/// struct NewUser {
+///     name: String,
+///     email: Possible<String>,
+///     age: Possible<i32>,
+/// }
+///
+/// let user = NewUser {
+///     name: String::from("Ferris"),
+///     email: Possible::None,
+///     age: Possible::Void,
+/// };
+///
+/// let query = diesel::insert_into(users::table).values(user);
+/// assert_eq!(
+///     debug_query::<Sqlite, _>(&query).to_string(),
+///     "INSERT INTO `users` (`name`, `email`) VALUES (?, ?) -- binds: [\"Ferris\", None]",
+/// );
+/// ```
+#[proc_macro_derive(Insertable, attributes(diesel))]
+pub fn derive_insertable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    sql::expand(input, sql::Derive::Insertable)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Adds `#[serde(default, skip_serializing_if = "Possible::is_void")]` to every `Possible`
+/// field of a struct.
+///
+/// Without these attributes, a missing field is parsed as `Possible::None` instead of
+/// `Possible::Void`, and a `Possible::Void` field is serialized as a null value instead of
+/// being omitted. This attribute must be placed above `#[derive(Serialize, Deserialize)]`
+/// so that the derives see the added attributes.
+///
+/// Fields are recognized by their type being written as `Possible<T>`. A `Possible` field
+/// that already has a conflicting serde attribute, such as `default` or
+/// `skip_serializing_if`, is a compile error.
+///
+/// # Examples
+///
+/// ```
+/// use possible::Possible;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[possible::fields]
+/// #[derive(Debug, Serialize, Deserialize, PartialEq)]
+/// struct UserPatch {
+///     name: Possible<String>,
+///     #[serde(rename = "emailAddress")]
+///     email: Possible<String>,
+/// }
+///
+/// let patch: UserPatch = serde_json::from_str(r#"{ "emailAddress": null }"#).unwrap();
+/// assert_eq!(patch, UserPatch {
+///     name: Possible::Void,
+///     email: Possible::None,
+/// });
+///
+/// assert_eq!(serde_json::to_string(&patch).unwrap(), r#"{"emailAddress":null}"#);
+/// ```
+#[proc_macro_attribute]
+pub fn fields(arguments: TokenStream, input: TokenStream) -> TokenStream {
+    if let Err(error) = fields::check_arguments(arguments.into()) {
+        return error.into_compile_error().into();
+    }
+
+    let input = parse_macro_input!(input as ItemStruct);
+
+    fields::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
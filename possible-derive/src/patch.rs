@@ -0,0 +1,329 @@
+use crate::util::option_inner;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{
+    punctuated::Punctuated, Data, DeriveInput, Error, Fields, Ident, LitStr, Path, Result, Token,
+    Type, Visibility,
+};
+
+struct Container {
+    name: Option<Ident>,
+    derives: Vec<Path>,
+    active_model: Option<Path>,
+}
+
+struct Field<'a> {
+    ident: &'a Ident,
+    vis: &'a Visibility,
+    ty: &'a Type,
+    /// The `T` of a field declared as `Option<T>`.
+    nullable: Option<&'a Type>,
+    /// Whether the field is patched recursively by its `Patchable::Patch` type.
+    nested: bool,
+}
+
+#[derive(Default)]
+struct FieldOptions {
+    skip: bool,
+    nested: bool,
+}
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let container = parse_container(&input)?;
+
+    let named = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "`Patch` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`Patch` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut fields = Vec::new();
+    for field in named {
+        let options = parse_field(field)?;
+        if options.skip {
+            continue;
+        }
+
+        fields.push(Field {
+            // named fields always have an identifier
+            ident: field.ident.as_ref().unwrap(),
+            vis: &field.vis,
+            ty: &field.ty,
+            nullable: option_inner(&field.ty),
+            nested: options.nested,
+        });
+    }
+
+    let target = &input.ident;
+    let vis = &input.vis;
+    let name = container
+        .name
+        .unwrap_or_else(|| format_ident!("{}Patch", target));
+    let derives = &container.derives;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let generics = &input.generics;
+
+    let doc = format!(
+        "Partial update of [`{}`], where `Possible::Void` fields are left unchanged.",
+        target
+    );
+
+    let declarations = fields.iter().map(|field| {
+        let Field { ident, vis, .. } = field;
+        let ty = field.nullable.unwrap_or(field.ty);
+        let ty = if field.nested {
+            quote!(<#ty as ::possible::Patchable>::Patch)
+        } else {
+            quote!(#ty)
+        };
+
+        quote! {
+            #[serde(default, skip_serializing_if = "::possible::Possible::is_void")]
+            #vis #ident: ::possible::Possible<#ty>
+        }
+    });
+
+    let applications = fields.iter().map(|field| {
+        let ident = field.ident;
+        let name = ident.unraw().to_string();
+        let application = match (field.nested, field.nullable) {
+            (true, Some(_)) => quote!(self.#ident.apply_nested_option(&mut target.#ident)),
+            (true, None) => quote!(self.#ident.apply_nested(&mut target.#ident)),
+            (false, _) => quote!(::possible::Patch::apply(self.#ident, &mut target.#ident)),
+        };
+
+        quote! {
+            #application.map_err(|error| ::possible::PatchError::field(#name, error))?;
+        }
+    });
+
+    let checks = fields.iter().map(|field| {
+        let ident = field.ident;
+        let name = ident.unraw().to_string();
+        let check = match (field.nested, field.nullable) {
+            (true, Some(_)) => quote!(self.#ident.check_nested_option(&target.#ident)),
+            (true, None) => quote!(self.#ident.check_nested(&target.#ident)),
+            (false, _) => quote!(::possible::Patch::check(&self.#ident, &target.#ident)),
+        };
+
+        quote! {
+            #check.map_err(|error| ::possible::PatchError::field(#name, error))?;
+        }
+    });
+
+    let merges = fields.iter().map(|field| {
+        let ident = field.ident;
+
+        match (field.nested, field.nullable) {
+            (true, Some(ty)) => {
+                quote!(#ident: self.#ident.merge_nested_option::<#ty>(newer.#ident))
+            }
+            (true, None) => quote!(#ident: self.#ident.merge_nested(newer.#ident)),
+            (false, Some(_)) => quote!(#ident: self.#ident.merge(newer.#ident)),
+            (false, None) => quote!(#ident: self.#ident.merge_non_nullable(newer.#ident)),
+        }
+    });
+
+    let conversions = fields.iter().map(|field| {
+        let ident = field.ident;
+
+        match (field.nested, field.nullable) {
+            (true, Some(_)) => quote! {
+                #ident: match value.#ident {
+                    ::core::option::Option::Some(value) => {
+                        ::possible::Possible::Some(::possible::Patchable::into_patch(value))
+                    }
+                    ::core::option::Option::None => ::possible::Possible::None,
+                }
+            },
+            (true, None) => quote! {
+                #ident: ::possible::Possible::Some(::possible::Patchable::into_patch(value.#ident))
+            },
+            (false, Some(_)) => quote!(#ident: ::possible::Possible::from(value.#ident)),
+            (false, None) => quote!(#ident: ::possible::Possible::Some(value.#ident)),
+        }
+    });
+
+    let active_model = match &container.active_model {
+        Some(active_model) => {
+            if let Some(field) = fields.iter().find(|field| field.nested) {
+                return Err(Error::new_spanned(
+                    field.ident,
+                    "`patch(nested)` fields cannot be applied to an `active_model`",
+                ));
+            }
+
+            let applications = fields.iter().map(|field| {
+                let ident = field.ident;
+                let name = ident.unraw().to_string();
+
+                quote! {
+                    ::possible::Patch::apply(self.#ident, &mut target.#ident)
+                        .map_err(|error| ::possible::PatchError::field(#name, error))?;
+                }
+            });
+            let checks = fields.iter().map(|field| {
+                let ident = field.ident;
+                let name = ident.unraw().to_string();
+
+                quote! {
+                    ::possible::Patch::check(&self.#ident, &target.#ident)
+                        .map_err(|error| ::possible::PatchError::field(#name, error))?;
+                }
+            });
+
+            quote! {
+                impl #impl_generics ::possible::Patch<#active_model> for #name #ty_generics #where_clause {
+                    fn apply(
+                        self,
+                        target: &mut #active_model,
+                    ) -> ::core::result::Result<(), ::possible::PatchError> {
+                        // every field is checked first, so that an error leaves `target` unchanged
+                        ::possible::Patch::check(&self, target)?;
+                        #(#applications)*
+                        ::core::result::Result::Ok(())
+                    }
+
+                    fn check(
+                        &self,
+                        target: &#active_model,
+                    ) -> ::core::result::Result<(), ::possible::PatchError> {
+                        #(#checks)*
+                        ::core::result::Result::Ok(())
+                    }
+                }
+            }
+        }
+        None => TokenStream::new(),
+    };
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(
+            ::core::default::Default,
+            ::possible::__private::serde::Serialize,
+            ::possible::__private::serde::Deserialize,
+            #(#derives),*
+        )]
+        #[serde(crate = "::possible::__private::serde")]
+        #vis struct #name #generics #where_clause {
+            #(#declarations,)*
+        }
+
+        impl #impl_generics ::possible::Merge for #name #ty_generics #where_clause {
+            fn merge(self, newer: Self) -> Self {
+                Self {
+                    #(#merges,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::possible::Patchable for #target #ty_generics #where_clause {
+            type Patch = #name #ty_generics;
+
+            fn into_patch(self) -> Self::Patch {
+                let value = self;
+                #name {
+                    #(#conversions,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::possible::Patch<#target #ty_generics> for #name #ty_generics #where_clause {
+            fn apply(
+                self,
+                target: &mut #target #ty_generics,
+            ) -> ::core::result::Result<(), ::possible::PatchError> {
+                // every field is checked first, so that an error leaves `target` unchanged
+                ::possible::Patch::check(&self, target)?;
+                #(#applications)*
+                ::core::result::Result::Ok(())
+            }
+
+            fn check(
+                &self,
+                target: &#target #ty_generics,
+            ) -> ::core::result::Result<(), ::possible::PatchError> {
+                #(#checks)*
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        #active_model
+    })
+}
+
+fn parse_container(input: &DeriveInput) -> Result<Container> {
+    let mut container = Container {
+        name: None,
+        derives: Vec::new(),
+        active_model: None,
+    };
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("patch"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                let name: LitStr = meta.value()?.parse()?;
+                container.name = Some(name.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("derive") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let paths = Punctuated::<Path, Token![,]>::parse_terminated(&content)?;
+                container.derives.extend(paths);
+                Ok(())
+            } else if meta.path.is_ident("active_model") {
+                let path: LitStr = meta.value()?.parse()?;
+                container.active_model = Some(path.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "unsupported `patch` attribute, expected `name`, `derive` or `active_model`",
+                ))
+            }
+        })?;
+    }
+
+    Ok(container)
+}
+
+fn parse_field(field: &syn::Field) -> Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("patch"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                options.skip = true;
+                Ok(())
+            } else if meta.path.is_ident("nested") {
+                options.nested = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `patch` attribute, expected `skip` or `nested`"))
+            }
+        })?;
+    }
+
+    Ok(options)
+}
@@ -0,0 +1,285 @@
+use crate::util::{option_inner, possible_inner};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{
+    meta::ParseNestedMeta, punctuated::Punctuated, Data, DeriveInput, Error, Fields, Ident,
+    LitBool, Path, Result, Token, Type,
+};
+
+/// The `diesel` derive a struct is expanded for.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Derive {
+    AsChangeset,
+    Insertable,
+}
+
+struct Container {
+    table_name: Option<Path>,
+    primary_key: Option<Vec<Ident>>,
+    treat_none_as_null: bool,
+}
+
+struct Field<'a> {
+    ident: &'a Ident,
+    ty: &'a Type,
+    column: Ident,
+}
+
+#[derive(Default)]
+struct FieldOptions {
+    column_name: Option<Ident>,
+    skip_update: bool,
+    skip_insertion: bool,
+}
+
+/// Diesel attributes that change how fields are written and are not supported.
+const UNSUPPORTED: &[&str] = &[
+    "embed",
+    "serialize_as",
+    "treat_none_as_default_value",
+    "treat_none_as_null",
+];
+
+/// Diesel attributes that are only used by other diesel derives, and are skipped.
+const OTHER_DERIVES: &[&str] = &[
+    "aggregate",
+    "base_query",
+    "base_query_type",
+    "belongs_to",
+    "check_for_backend",
+    "deserialize_as",
+    "foreign_derive",
+    "mysql_type",
+    "postgres_type",
+    "select_expression",
+    "select_expression_type",
+    "sql_type",
+    "sqlite_type",
+];
+
+pub fn expand(input: DeriveInput, derive: Derive) -> Result<TokenStream> {
+    let name = match derive {
+        Derive::AsChangeset => "AsChangeset",
+        Derive::Insertable => "Insertable",
+    };
+    let container = parse_container(&input)?;
+
+    let named = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    format_args!(
+                        "`{}` can only be derived for structs with named fields",
+                        name
+                    ),
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                format_args!("`{}` can only be derived for structs", name),
+            ))
+        }
+    };
+
+    let primary_key = container
+        .primary_key
+        .unwrap_or_else(|| vec![format_ident!("id")]);
+
+    let mut fields = Vec::new();
+    for field in named {
+        let options = parse_field(field)?;
+        // named fields always have an identifier
+        let ident = field.ident.as_ref().unwrap();
+        let column = options
+            .column_name
+            .unwrap_or_else(|| Ident::new(&ident.unraw().to_string(), ident.span()));
+
+        let skip = match derive {
+            Derive::AsChangeset => options.skip_update || primary_key.contains(&column),
+            Derive::Insertable => options.skip_insertion,
+        };
+        if skip {
+            continue;
+        }
+
+        fields.push(Field {
+            ident,
+            ty: &field.ty,
+            column,
+        });
+    }
+
+    if fields.is_empty() {
+        return Err(Error::new_spanned(
+            &input.ident,
+            format_args!("`{}` requires at least one column", name),
+        ));
+    }
+
+    let table = container.table_name.unwrap_or_else(|| {
+        let table = format_ident!("{}", infer_table_name(&input.ident.unraw().to_string()));
+        table.into()
+    });
+    let none_as_null = container.treat_none_as_null;
+    let diesel = quote!(::possible::__private::diesel);
+
+    let mut types = Vec::new();
+    let mut values = Vec::new();
+    for field in &fields {
+        let Field { ident, ty, column } = field;
+        let column = quote!(#table::#column);
+
+        let nullable = option_inner(ty).filter(|_| derive == Derive::Insertable || !none_as_null);
+
+        if possible_inner(ty).is_some() {
+            types.push(quote!(::core::option::Option<#diesel::dsl::Eq<#column, #ty>>));
+            values.push(quote!(::possible::sql::assign(#column, self.#ident)));
+        } else if let Some(inner) = nullable {
+            types.push(quote!(::core::option::Option<#diesel::dsl::Eq<#column, #inner>>));
+            values.push(quote! {
+                self.#ident.map(|value| #diesel::ExpressionMethods::eq(#column, value))
+            });
+        } else {
+            types.push(quote!(#diesel::dsl::Eq<#column, #ty>));
+            values.push(quote!(#diesel::ExpressionMethods::eq(#column, self.#ident)));
+        }
+    }
+
+    let target = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(match derive {
+        Derive::AsChangeset => quote! {
+            impl #impl_generics #diesel::query_builder::AsChangeset for #target #ty_generics #where_clause {
+                type Target = #table::table;
+                type Changeset = <(#(#types,)*) as #diesel::query_builder::AsChangeset>::Changeset;
+
+                fn as_changeset(self) -> Self::Changeset {
+                    #diesel::query_builder::AsChangeset::as_changeset((#(#values,)*))
+                }
+            }
+        },
+        Derive::Insertable => quote! {
+            impl #impl_generics #diesel::Insertable<#table::table> for #target #ty_generics #where_clause {
+                type Values = <(#(#types,)*) as #diesel::Insertable<#table::table>>::Values;
+
+                fn values(self) -> Self::Values {
+                    #diesel::Insertable::<#table::table>::values((#(#values,)*))
+                }
+            }
+        },
+    })
+}
+
+fn parse_container(input: &DeriveInput) -> Result<Container> {
+    let mut container = Container {
+        table_name: None,
+        primary_key: None,
+        treat_none_as_null: false,
+    };
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("diesel"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("table_name") {
+                container.table_name = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("primary_key") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let columns = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+                container.primary_key = Some(columns.into_iter().collect());
+                Ok(())
+            } else if meta.path.is_ident("treat_none_as_null") {
+                let value: LitBool = meta.value()?.parse()?;
+                container.treat_none_as_null = value.value;
+                Ok(())
+            } else {
+                skip_meta(&meta)
+            }
+        })?;
+    }
+
+    Ok(container)
+}
+
+fn parse_field(field: &syn::Field) -> Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("diesel"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("column_name") {
+                let column: Ident = meta.value()?.call(Ident::parse_any)?;
+                options.column_name = Some(column);
+                Ok(())
+            } else if meta.path.is_ident("skip_update") {
+                options.skip_update = true;
+                Ok(())
+            } else if meta.path.is_ident("skip_insertion") {
+                options.skip_insertion = true;
+                Ok(())
+            } else {
+                skip_meta(&meta)
+            }
+        })?;
+    }
+
+    Ok(options)
+}
+
+/// Consumes a diesel attribute that is only used by other diesel derives, or rejects one
+/// that changes how fields are written or is unknown.
+fn skip_meta(meta: &ParseNestedMeta) -> Result<()> {
+    if let Some(name) = UNSUPPORTED.iter().find(|name| meta.path.is_ident(name)) {
+        return Err(meta.error(format_args!(
+            "`diesel({})` is not supported by the `possible::sql` derives",
+            name
+        )));
+    }
+    if !OTHER_DERIVES.iter().any(|name| meta.path.is_ident(name)) {
+        return Err(meta.error("unknown `diesel` attribute"));
+    }
+
+    if meta.input.peek(Token![=]) {
+        meta.input.parse::<Token![=]>()?;
+        while !meta.input.is_empty() && !meta.input.peek(Token![,]) {
+            meta.input.parse::<proc_macro2::TokenTree>()?;
+        }
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.input.parse::<proc_macro2::Group>()?;
+    }
+
+    Ok(())
+}
+
+/// Infers the table of a struct the same way diesel does, as its name in snake case
+/// followed by an `s`.
+fn infer_table_name(name: &str) -> String {
+    let mut table = String::with_capacity(name.len() + 1);
+
+    for (index, character) in name.chars().enumerate() {
+        if character.is_uppercase() {
+            if index > 0 {
+                table.push('_');
+            }
+            table.extend(character.to_lowercase());
+        } else {
+            table.push(character);
+        }
+    }
+
+    table.push('s');
+    table
+}
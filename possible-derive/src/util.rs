@@ -0,0 +1,35 @@
+use syn::{GenericArgument, PathArguments, Type};
+
+/// Returns the `T` of a type written as `Option<T>`, if the type is an `Option`.
+///
+/// This is a syntactic check, so aliases of `Option` are treated as any other type.
+pub fn option_inner(ty: &Type) -> Option<&Type> {
+    generic_inner(ty, "Option")
+}
+
+/// Returns the `T` of a type written as `Possible<T>`, if the type is a `Possible`.
+pub fn possible_inner(ty: &Type) -> Option<&Type> {
+    generic_inner(ty, "Possible")
+}
+
+fn generic_inner<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let path = match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => &type_path.path,
+        _ => return None,
+    };
+
+    let segment = path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => {
+            match arguments.args.first()? {
+                GenericArgument::Type(inner) => Some(inner),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,106 @@
+mod with_possible {
+    use possible::Possible;
+    use serde::{Deserialize, Serialize};
+
+    #[possible::fields]
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct ParseTest {
+        test: Possible<i64>,
+        #[serde(rename = "renamed")]
+        other: Possible<String>,
+        required: Option<u8>,
+    }
+
+    mod serialization {
+        use super::{ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_some_value() {
+            let data = ParseTest {
+                test: Possible::Some(123),
+                ..Default::default()
+            };
+            let serialized = serde_json::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, r#"{"test":123,"required":null}"#,
+                "Failed to serialize expected number value"
+            );
+        }
+
+        #[test]
+        fn with_null_value() {
+            let data = ParseTest {
+                other: Possible::None,
+                ..Default::default()
+            };
+            let serialized = serde_json::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, r#"{"renamed":null,"required":null}"#,
+                "Failed to serialize expected null value"
+            );
+        }
+
+        #[test]
+        fn with_no_field() {
+            let data = ParseTest::default();
+            let serialized = serde_json::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, r#"{"required":null}"#,
+                "Failed to serialize expected field omission"
+            );
+        }
+    }
+
+    mod deserialization {
+        use super::{ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_some_value() {
+            let json = r#"{ "test": 123, "renamed": "value" }"#;
+            let parsed: ParseTest = serde_json::from_str(json).unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest {
+                    test: Possible::Some(123),
+                    other: Possible::Some(String::from("value")),
+                    required: None,
+                },
+                "Failed to parse expected values"
+            );
+        }
+
+        #[test]
+        fn with_null_value() {
+            let json = r#"{ "test": null, "required": 1 }"#;
+            let parsed: ParseTest = serde_json::from_str(json).unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest {
+                    test: Possible::None,
+                    other: Possible::Void,
+                    required: Some(1),
+                },
+                "Failed to parse expected null value"
+            );
+        }
+
+        #[test]
+        fn with_no_field() {
+            let json = r#"{ }"#;
+            let parsed: ParseTest = serde_json::from_str(json).unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest::default(),
+                "Failed to parse expected field omission"
+            );
+        }
+    }
+}
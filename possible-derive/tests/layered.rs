@@ -0,0 +1,327 @@
+use possible::{
+    layer::{LayerError, Layered, Source, Stack},
+    Merge, Possible,
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Layered)]
+#[layered(name = "Config", derive(Debug, PartialEq))]
+pub struct ConfigLayer {
+    #[layered(nested)]
+    server: Possible<ServerLayer>,
+    proxy: Possible<String>,
+    r#type: Possible<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Layered)]
+#[layered(derive(Debug, PartialEq))]
+pub struct ServerLayer {
+    #[layered(required)]
+    host: Possible<String>,
+    #[layered(required)]
+    port: Possible<u16>,
+}
+
+fn defaults() -> ConfigLayer {
+    ConfigLayer {
+        server: Possible::Some(ServerLayer {
+            host: Possible::Some(String::from("localhost")),
+            port: Possible::Some(8080),
+        }),
+        proxy: Possible::Some(String::from("http://proxy")),
+        r#type: Possible::Void,
+    }
+}
+
+fn set(layer: &str) -> Option<Source> {
+    Some(Source::Set(String::from(layer)))
+}
+
+fn cleared(layer: &str) -> Option<Source> {
+    Some(Source::Cleared(String::from(layer)))
+}
+
+mod resolve {
+    use super::{defaults, Config, ConfigLayer, Possible, ServerLayer, ServerLayerResolved, Stack};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_single_layer() {
+        let resolved = Stack::new().push("defaults", defaults()).resolve().unwrap();
+
+        assert_eq!(
+            resolved.value,
+            Config {
+                server: ServerLayerResolved {
+                    host: String::from("localhost"),
+                    port: 8080,
+                },
+                proxy: Some(String::from("http://proxy")),
+                r#type: None,
+            },
+            "Failed to resolve single layer"
+        );
+    }
+
+    #[test]
+    fn with_overriding_layers() {
+        let env = ConfigLayer {
+            server: Possible::Some(ServerLayer {
+                host: Possible::Void,
+                port: Possible::Some(80),
+            }),
+            proxy: Possible::None,
+            r#type: Possible::Void,
+        };
+        let cli = ConfigLayer {
+            server: Possible::Void,
+            proxy: Possible::Void,
+            r#type: Possible::Some(String::from("admin")),
+        };
+        let resolved = Stack::new()
+            .push("defaults", defaults())
+            .push("env", env)
+            .push("cli", cli)
+            .resolve()
+            .unwrap();
+
+        assert_eq!(
+            resolved.value,
+            Config {
+                server: ServerLayerResolved {
+                    host: String::from("localhost"),
+                    port: 80,
+                },
+                proxy: None,
+                r#type: Some(String::from("admin")),
+            },
+            "Failed to override earlier layers"
+        );
+    }
+
+    #[test]
+    fn with_nested_layer_set_after_cleared() {
+        let file = ConfigLayer {
+            server: Possible::None,
+            ..ConfigLayer::default()
+        };
+        let env = ConfigLayer {
+            server: Possible::Some(ServerLayer {
+                host: Possible::Some(String::from("example.com")),
+                port: Possible::Some(443),
+            }),
+            ..ConfigLayer::default()
+        };
+        let resolved = Stack::new()
+            .push("defaults", defaults())
+            .push("file", file)
+            .push("env", env)
+            .resolve()
+            .unwrap();
+
+        assert_eq!(
+            resolved.value.server,
+            ServerLayerResolved {
+                host: String::from("example.com"),
+                port: 443,
+            },
+            "Failed to set nested layer cleared by an earlier layer"
+        );
+    }
+
+    #[test]
+    fn with_no_layers() {
+        let resolved = Stack::<ConfigLayer>::new().resolve();
+
+        assert!(
+            resolved.is_err(),
+            "Failed to reject missing required fields"
+        );
+    }
+}
+
+mod provenance {
+    use super::{cleared, defaults, set, ConfigLayer, Possible, ServerLayer, Stack};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_overriding_layers() {
+        let env = ConfigLayer {
+            server: Possible::Some(ServerLayer {
+                host: Possible::Void,
+                port: Possible::Some(80),
+            }),
+            proxy: Possible::None,
+            r#type: Possible::Void,
+        };
+        let resolved = Stack::new()
+            .push("defaults", defaults())
+            .push("env", env)
+            .resolve()
+            .unwrap();
+        let provenance = &resolved.provenance;
+
+        assert_eq!(
+            [
+                provenance.get("server.host").cloned(),
+                provenance.get("server.port").cloned(),
+                provenance.get("proxy").cloned(),
+                provenance.get("type").cloned(),
+            ],
+            [set("defaults"), set("env"), cleared("env"), None],
+            "Failed to record the layer of each field"
+        );
+    }
+
+    #[test]
+    fn with_cleared_nested_layer() {
+        let env = ConfigLayer {
+            server: Possible::None,
+            ..ConfigLayer::default()
+        };
+        let cli = ConfigLayer {
+            server: Possible::Some(ServerLayer {
+                host: Possible::Some(String::from("example.com")),
+                port: Possible::Void,
+            }),
+            ..ConfigLayer::default()
+        };
+        let (_, provenance) = Stack::new()
+            .push("defaults", defaults())
+            .push("env", env)
+            .push("cli", cli)
+            .fold();
+
+        assert_eq!(
+            provenance
+                .iter()
+                .map(|(path, source)| (path, source.layer()))
+                .collect::<Vec<_>>(),
+            [("proxy", "defaults"), ("server.host", "cli")],
+            "Failed to replace the provenance of a cleared nested layer"
+        );
+    }
+}
+
+mod errors {
+    use super::{defaults, ConfigLayer, LayerError, Possible, ServerLayer, Stack};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_missing_field() {
+        let file = ConfigLayer {
+            server: Possible::Some(ServerLayer {
+                host: Possible::Some(String::from("localhost")),
+                port: Possible::Void,
+            }),
+            ..ConfigLayer::default()
+        };
+        let error = Stack::new().push("file", file).resolve().unwrap_err();
+
+        assert_eq!(
+            error,
+            LayerError::Missing(String::from("server.port")),
+            "Failed to reject missing field"
+        );
+        assert_eq!(error.to_string(), "field `server.port` is not set");
+    }
+
+    #[test]
+    fn with_cleared_field() {
+        let env = ConfigLayer {
+            server: Possible::Some(ServerLayer {
+                host: Possible::None,
+                port: Possible::Void,
+            }),
+            ..ConfigLayer::default()
+        };
+        let error = Stack::new()
+            .push("defaults", defaults())
+            .push("env", env)
+            .resolve()
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            LayerError::Cleared(String::from("server.host"), String::from("env")),
+            "Failed to reject cleared field"
+        );
+        assert_eq!(
+            error.to_string(),
+            "field `server.host` is cleared by layer `env`"
+        );
+    }
+
+    #[test]
+    fn with_cleared_nested_layer() {
+        let env = ConfigLayer {
+            server: Possible::None,
+            ..ConfigLayer::default()
+        };
+        let error = Stack::new()
+            .push("defaults", defaults())
+            .push("env", env)
+            .resolve()
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            LayerError::Cleared(String::from("server"), String::from("env")),
+            "Failed to reject cleared nested layer"
+        );
+    }
+
+    #[test]
+    fn with_partial_nested_layer_set_after_cleared() {
+        let file = ConfigLayer {
+            server: Possible::None,
+            ..ConfigLayer::default()
+        };
+        let env = ConfigLayer {
+            server: Possible::Some(ServerLayer {
+                host: Possible::Some(String::from("example.com")),
+                port: Possible::Void,
+            }),
+            ..ConfigLayer::default()
+        };
+        let error = Stack::new()
+            .push("defaults", defaults())
+            .push("file", file)
+            .push("env", env)
+            .resolve()
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            LayerError::Missing(String::from("server.port")),
+            "Failed to start over from the default of a cleared nested layer"
+        );
+    }
+}
+
+mod merge {
+    use super::{defaults, ConfigLayer, Merge, Possible, ServerLayer};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_nested_layer() {
+        let env = ConfigLayer {
+            server: Possible::Some(ServerLayer {
+                host: Possible::Void,
+                port: Possible::Some(80),
+            }),
+            ..ConfigLayer::default()
+        };
+
+        assert_eq!(
+            defaults().merge(env),
+            ConfigLayer {
+                server: Possible::Some(ServerLayer {
+                    host: Possible::Some(String::from("localhost")),
+                    port: Possible::Some(80),
+                }),
+                ..defaults()
+            },
+            "Failed to merge nested layer"
+        );
+    }
+}
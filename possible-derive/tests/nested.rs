@@ -0,0 +1,248 @@
+use possible::{Patch, PatchError, Possible};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Default, PartialEq, Patch)]
+#[patch(derive(Debug, Clone, PartialEq))]
+pub struct Address {
+    city: String,
+    zip: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Patch)]
+#[patch(derive(Debug, Clone, PartialEq))]
+pub struct Profile {
+    #[patch(nested)]
+    home: Address,
+    #[patch(nested)]
+    work: Option<Address>,
+    #[patch(nested)]
+    labels: HashMap<String, String>,
+}
+
+fn ferris() -> Profile {
+    let mut labels = HashMap::new();
+    labels.insert(String::from("color"), String::from("orange"));
+    labels.insert(String::from("size"), String::from("small"));
+
+    Profile {
+        home: Address {
+            city: String::from("Toronto"),
+            zip: Some(String::from("M5V")),
+        },
+        work: None,
+        labels,
+    }
+}
+
+mod apply {
+    use super::{ferris, Address, Patch, PatchError, Profile, ProfilePatch};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_nested_struct() {
+        let mut profile = ferris();
+        let patch: ProfilePatch = serde_json::from_str(r#"{ "home": { "zip": null } }"#).unwrap();
+        patch.apply(&mut profile).unwrap();
+
+        assert_eq!(
+            profile,
+            Profile {
+                home: Address {
+                    city: String::from("Toronto"),
+                    zip: None,
+                },
+                ..ferris()
+            },
+            "Failed to patch nested struct recursively"
+        );
+    }
+
+    #[test]
+    fn with_absent_nested_struct() {
+        let mut profile = ferris();
+        let patch: ProfilePatch =
+            serde_json::from_str(r#"{ "work": { "city": "Vancouver" } }"#).unwrap();
+        patch.apply(&mut profile).unwrap();
+
+        assert_eq!(
+            profile.work,
+            Some(Address {
+                city: String::from("Vancouver"),
+                zip: None,
+            }),
+            "Failed to create nested struct from default"
+        );
+    }
+
+    #[test]
+    fn with_null_nested_struct() {
+        let mut profile = ferris();
+        profile.work = Some(Address::default());
+        let patch: ProfilePatch = serde_json::from_str(r#"{ "work": null }"#).unwrap();
+        patch.apply(&mut profile).unwrap();
+
+        assert_eq!(profile, ferris(), "Failed to clear nullable nested struct");
+    }
+
+    #[test]
+    fn with_null_non_nullable_nested_struct() {
+        let mut profile = ferris();
+        let patch: ProfilePatch = serde_json::from_str(r#"{ "home": { "city": null } }"#).unwrap();
+
+        assert_eq!(
+            patch.apply(&mut profile),
+            Err(PatchError::field(
+                "home",
+                PatchError::field("city", PatchError::NotNullable)
+            )),
+            "Failed to report path of non-nullable field"
+        );
+    }
+
+    #[test]
+    fn with_error_in_later_nested_struct() {
+        let mut profile = ferris();
+        let patch: ProfilePatch =
+            serde_json::from_str(r#"{ "home": { "zip": null }, "work": { "city": null } }"#)
+                .unwrap();
+
+        assert_eq!(
+            patch.apply(&mut profile),
+            Err(PatchError::field(
+                "work",
+                PatchError::field("city", PatchError::NotNullable)
+            )),
+            "Failed to report path of non-nullable field"
+        );
+        assert_eq!(
+            profile,
+            ferris(),
+            "Failed to leave target unchanged after error"
+        );
+    }
+
+    #[test]
+    fn with_nested_map() {
+        let mut profile = ferris();
+        let patch: ProfilePatch = serde_json::from_str(
+            r#"{ "labels": { "color": "red", "size": null, "shape": "crab" } }"#,
+        )
+        .unwrap();
+        patch.apply(&mut profile).unwrap();
+
+        let mut labels = profile.labels.into_iter().collect::<Vec<_>>();
+        labels.sort();
+
+        assert_eq!(
+            labels,
+            vec![
+                (String::from("color"), String::from("red")),
+                (String::from("shape"), String::from("crab")),
+            ],
+            "Failed to patch map entries"
+        );
+    }
+}
+
+mod merge {
+    use super::{ferris, Address, Patch, ProfilePatch};
+    use possible::Merge;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_nested_patches() {
+        let older: ProfilePatch = serde_json::from_str(
+            r#"{ "home": { "city": "Montreal" }, "labels": { "size": null } }"#,
+        )
+        .unwrap();
+        let newer: ProfilePatch =
+            serde_json::from_str(r#"{ "home": { "zip": "H2X" }, "labels": { "color": "red" } }"#)
+                .unwrap();
+
+        let mut sequential = ferris();
+        older.clone().apply(&mut sequential).unwrap();
+        newer.clone().apply(&mut sequential).unwrap();
+
+        let mut merged = ferris();
+        older.merge(newer).apply(&mut merged).unwrap();
+
+        assert_eq!(
+            merged, sequential,
+            "Failed to merge nested patches recursively"
+        );
+    }
+
+    #[test]
+    fn with_cleared_then_set_nested_struct() {
+        let mut profile = ferris();
+        profile.work = Some(Address {
+            city: String::new(),
+            zip: Some(String::from("Z")),
+        });
+        let older: ProfilePatch = serde_json::from_str(r#"{ "work": null }"#).unwrap();
+        let newer: ProfilePatch = serde_json::from_str(r#"{ "work": { "city": "V" } }"#).unwrap();
+
+        let mut sequential = profile.clone();
+        older.clone().apply(&mut sequential).unwrap();
+        newer.clone().apply(&mut sequential).unwrap();
+
+        let mut merged = profile;
+        older.merge(newer).apply(&mut merged).unwrap();
+
+        assert_eq!(
+            sequential.work,
+            Some(Address {
+                city: String::from("V"),
+                zip: None,
+            }),
+            "Failed to apply nested struct to default after clearing"
+        );
+        assert_eq!(
+            merged, sequential,
+            "Failed to merge cleared nested struct with newer patch"
+        );
+    }
+
+    #[test]
+    fn with_null_then_set_non_nullable_nested_struct() {
+        let older: ProfilePatch = serde_json::from_str(r#"{ "home": null }"#).unwrap();
+        let newer: ProfilePatch = serde_json::from_str(r#"{ "home": { "city": "V" } }"#).unwrap();
+
+        let mut sequential = ferris();
+        let sequential_result = older
+            .clone()
+            .apply(&mut sequential)
+            .and_then(|()| newer.clone().apply(&mut sequential));
+
+        let mut merged = ferris();
+        let merged_result = older.merge(newer).apply(&mut merged);
+
+        assert_eq!(
+            merged_result, sequential_result,
+            "Failed to keep error of null non-nullable nested struct"
+        );
+    }
+}
+
+mod serialization {
+    use super::{AddressPatch, Possible, ProfilePatch};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_nested_patch() {
+        let patch = ProfilePatch {
+            home: Possible::Some(AddressPatch {
+                city: Possible::Void,
+                zip: Possible::None,
+            }),
+            work: Possible::None,
+            labels: Possible::Void,
+        };
+        let serialized = serde_json::to_string(&patch).unwrap();
+
+        assert_eq!(
+            serialized, r#"{"home":{"zip":null},"work":null}"#,
+            "Failed to serialize expected nested patch"
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use possible::{Patch, PatchError, Possible};
+
+#[derive(Debug, Clone, PartialEq, Patch)]
+#[patch(derive(Debug, Clone, PartialEq))]
+pub struct User {
+    #[patch(skip)]
+    id: u64,
+    name: String,
+    email: Option<String>,
+    age: Option<u8>,
+}
+
+fn ferris() -> User {
+    User {
+        id: 1324,
+        name: String::from("Ferris"),
+        email: Some(String::from("ferris@example.com")),
+        age: Some(7),
+    }
+}
+
+mod apply {
+    use super::{ferris, Patch, PatchError, Possible, User, UserPatch};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_void_fields() {
+        let mut user = ferris();
+        UserPatch::default().apply(&mut user).unwrap();
+
+        assert_eq!(user, ferris(), "Failed to leave void fields unchanged");
+    }
+
+    #[test]
+    fn with_null_fields() {
+        let mut user = ferris();
+        let patch = UserPatch {
+            name: Possible::Void,
+            email: Possible::None,
+            age: Possible::Void,
+        };
+        patch.apply(&mut user).unwrap();
+
+        assert_eq!(
+            user,
+            User {
+                email: None,
+                ..ferris()
+            },
+            "Failed to clear nullable fields"
+        );
+    }
+
+    #[test]
+    fn with_some_fields() {
+        let mut user = ferris();
+        let patch = UserPatch {
+            name: Possible::Some(String::from("Corro")),
+            email: Possible::Void,
+            age: Possible::Some(8),
+        };
+        patch.apply(&mut user).unwrap();
+
+        assert_eq!(
+            user,
+            User {
+                name: String::from("Corro"),
+                age: Some(8),
+                ..ferris()
+            },
+            "Failed to overwrite fields"
+        );
+    }
+
+    #[test]
+    fn with_null_non_nullable_field() {
+        let mut user = ferris();
+        let patch = UserPatch {
+            name: Possible::None,
+            email: Possible::Void,
+            age: Possible::Void,
+        };
+
+        assert_eq!(
+            patch.apply(&mut user),
+            Err(PatchError::field("name", PatchError::NotNullable)),
+            "Failed to reject null for non-nullable field"
+        );
+    }
+
+    #[test]
+    fn with_error_after_valid_field() {
+        #[derive(Debug, PartialEq, Patch)]
+        struct Counter {
+            limit: Option<u32>,
+            count: u32,
+        }
+
+        let mut counter = Counter {
+            limit: Some(1),
+            count: 2,
+        };
+        let patch = CounterPatch {
+            limit: Possible::None,
+            count: Possible::None,
+        };
+
+        assert_eq!(
+            patch.apply(&mut counter),
+            Err(PatchError::field("count", PatchError::NotNullable)),
+            "Failed to reject null for non-nullable field"
+        );
+        assert_eq!(
+            counter,
+            Counter {
+                limit: Some(1),
+                count: 2,
+            },
+            "Failed to leave target unchanged after error"
+        );
+    }
+}
+
+mod merge {
+    use super::{ferris, Patch, Possible, UserPatch};
+    use possible::Merge;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_newer_states() {
+        let older = UserPatch {
+            name: Possible::Some(String::from("Corro")),
+            email: Possible::Some(String::from("corro@example.com")),
+            age: Possible::Some(8),
+        };
+        let newer = UserPatch {
+            name: Possible::Void,
+            email: Possible::None,
+            age: Possible::Some(9),
+        };
+
+        assert_eq!(
+            older.merge(newer),
+            UserPatch {
+                name: Possible::Some(String::from("Corro")),
+                email: Possible::None,
+                age: Possible::Some(9),
+            },
+            "Failed to prefer non-void newer fields"
+        );
+    }
+
+    #[test]
+    fn with_null_then_set_non_nullable_field() {
+        let older = UserPatch {
+            name: Possible::None,
+            email: Possible::None,
+            age: Possible::Void,
+        };
+        let newer = UserPatch {
+            name: Possible::Some(String::from("Corro")),
+            email: Possible::Some(String::from("corro@example.com")),
+            age: Possible::Void,
+        };
+
+        let mut sequential = ferris();
+        let sequential = older
+            .clone()
+            .apply(&mut sequential)
+            .and_then(|_| newer.clone().apply(&mut sequential));
+        let mut merged = ferris();
+        let merged = older.merge(newer).apply(&mut merged);
+
+        assert!(sequential.is_err(), "Failed to reject null non-nullable field");
+        assert_eq!(
+            merged, sequential,
+            "Failed to merge as applying each patch in turn"
+        );
+    }
+}
+
+mod serialization {
+    use super::{Possible, UserPatch};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_all_states() {
+        let patch = UserPatch {
+            name: Possible::Some(String::from("Corro")),
+            email: Possible::None,
+            age: Possible::Void,
+        };
+        let serialized = serde_json::to_string(&patch).unwrap();
+
+        assert_eq!(
+            serialized, r#"{"name":"Corro","email":null}"#,
+            "Failed to serialize expected patch"
+        );
+    }
+}
+
+mod deserialization {
+    use super::{Possible, UserPatch};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_all_states() {
+        let json = r#"{ "name": "Corro", "email": null }"#;
+        let parsed: UserPatch = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            parsed,
+            UserPatch {
+                name: Possible::Some(String::from("Corro")),
+                email: Possible::None,
+                age: Possible::Void,
+            },
+            "Failed to parse expected patch"
+        );
+    }
+
+    #[test]
+    fn with_skipped_field() {
+        let json = r#"{ "id": 1 }"#;
+        let parsed: UserPatch = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            parsed,
+            UserPatch::default(),
+            "Failed to ignore skipped field"
+        );
+    }
+}
+
+mod naming {
+    use possible::{Patch, Possible};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Patch)]
+    #[patch(name = "Settings")]
+    pub struct Config<T> {
+        value: T,
+    }
+
+    #[test]
+    fn with_custom_name_and_generics() {
+        let mut config = Config { value: 1u32 };
+        Settings {
+            value: Possible::Some(2),
+        }
+        .apply(&mut config)
+        .unwrap();
+
+        assert_eq!(config.value, 2);
+    }
+}
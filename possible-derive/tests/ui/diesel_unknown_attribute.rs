@@ -0,0 +1,8 @@
+#[derive(possible::sql::AsChangeset)]
+#[diesel(table_name = users, check_for_backend(diesel::sqlite::Sqlite))]
+struct UserChanges {
+    #[diesel(column_nam = name)]
+    name: possible::Possible<String>,
+}
+
+fn main() {}
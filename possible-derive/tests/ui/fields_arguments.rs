@@ -0,0 +1,7 @@
+#[possible::fields(default)]
+#[derive(serde::Deserialize)]
+struct UserPatch {
+    name: possible::Possible<String>,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[possible::fields]
+#[derive(serde::Deserialize)]
+struct UserPatch {
+    #[serde(flatten)]
+    name: possible::Possible<String>,
+}
+
+fn main() {}
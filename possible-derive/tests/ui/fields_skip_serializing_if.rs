@@ -0,0 +1,8 @@
+#[possible::fields]
+#[derive(serde::Serialize)]
+struct UserPatch {
+    #[serde(rename = "userName", skip_serializing_if = "possible::Possible::is_none")]
+    name: possible::Possible<String>,
+}
+
+fn main() {}
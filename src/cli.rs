@@ -0,0 +1,283 @@
+//! Command line support for `Possible`, behind the `clap` feature.
+//!
+//! An update command can tell apart an option that was left out from one that was cleared,
+//! such as `--description <VALUE>` to set a description, `--no-description` or
+//! `--description=` to clear it, and neither to leave it unchanged. These read as
+//! `Possible::Some`, `Possible::None`, and `Possible::Void`, which can be applied as a
+//! [`Patch`](crate::Patch) or passed on in a `#[derive(Patch)]` patch struct.
+//!
+//! - [`ClearableParser`] wraps a [`clap`](https://docs.rs/clap) value parser to read an
+//!   empty value as `Possible::None`.
+//! - [`augment_args`] adds such an option and its `--no-` flag to a `Command`, and
+//!   [`from_arg_matches`] reads them back as a `Possible`.
+//! - [`ClearableArg`] is such an option and its `--no-` flag as a field of a
+//!   `#[derive(Parser)]` struct, with `#[command(flatten)]`.
+//! - [`from_flags`] combines the fields of a `#[derive(Args)]` struct for the same pair.
+//!
+//! # Examples
+//!
+//! ```
+//! use clap::{builder::StringValueParser, Parser};
+//! use possible::{cli::ClearableParser, Possible};
+//!
+//! #[derive(Parser)]
+//! struct Update {
+//!     #[arg(long, value_parser = ClearableParser::new(StringValueParser::new()))]
+//!     description: Option<Possible<String>>,
+//!     #[arg(long, conflicts_with = "description")]
+//!     no_description: bool,
+//! }
+//!
+//! let args = Update::parse_from(["update", "--no-description"]);
+//! let description = possible::cli::from_flags(args.description, args.no_description);
+//! assert_eq!(description, Possible::None);
+//!
+//! let args = Update::parse_from(["update"]);
+//! let description = possible::cli::from_flags(args.description, args.no_description);
+//! assert_eq!(description, Possible::Void);
+//! ```
+
+use crate::Possible;
+use clap::{
+    builder::{PossibleValue, TypedValueParser},
+    Arg, ArgAction, ArgMatches, Args, Command, Error, FromArgMatches,
+};
+use std::{error::Error as StdError, ffi::OsStr, marker::PhantomData, str::FromStr};
+
+/// Value parser that reads an empty value as `Possible::None`, and any other value with
+/// the parser `P` as `Possible::Some`.
+///
+/// # Examples
+///
+/// ```
+/// use clap::{value_parser, Arg, Command};
+/// use possible::{cli::ClearableParser, Possible};
+///
+/// let command = Command::new("update").arg(
+///     Arg::new("limit")
+///         .long("limit")
+///         .value_parser(ClearableParser::new(value_parser!(u32))),
+/// );
+///
+/// let matches = command.clone().get_matches_from(["update", "--limit=10"]);
+/// assert_eq!(matches.get_one("limit"), Some(&Possible::Some(10u32)));
+///
+/// let matches = command.get_matches_from(["update", "--limit="]);
+/// assert_eq!(matches.get_one("limit"), Some(&Possible::<u32>::None));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ClearableParser<P>(P);
+
+impl<P: TypedValueParser> ClearableParser<P> {
+    /// Creates a value parser that reads values other than an empty value with `parser`.
+    pub fn new(parser: P) -> Self {
+        ClearableParser(parser)
+    }
+}
+
+impl<P: TypedValueParser> TypedValueParser for ClearableParser<P> {
+    type Value = Possible<P::Value>;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        if value.is_empty() {
+            Ok(Possible::None)
+        } else {
+            self.0.parse_ref(cmd, arg, value).map(Possible::Some)
+        }
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        self.0.possible_values()
+    }
+}
+
+/// Adds the option `--{name} <VALUE>`, read with `parser`, and the flag `--no-{name}` to
+/// `cmd`, so that [`from_arg_matches`] can read them as a `Possible`.
+///
+/// # Examples
+///
+/// ```
+/// use clap::{builder::StringValueParser, Command};
+/// use possible::Possible;
+///
+/// let command = possible::cli::augment_args(
+///     Command::new("update"),
+///     "description",
+///     StringValueParser::new(),
+/// );
+///
+/// let matches = command.get_matches_from(["update", "--description", "Crab"]);
+/// let description: Possible<String> = possible::cli::from_arg_matches(&matches, "description");
+/// assert_eq!(description, Possible::Some(String::from("Crab")));
+/// ```
+pub fn augment_args<P: TypedValueParser>(cmd: Command, name: &'static str, parser: P) -> Command {
+    let clear = format!("no-{}", name);
+
+    cmd.arg(
+        Arg::new(name)
+            .long(name)
+            .action(ArgAction::Set)
+            .value_parser(ClearableParser::new(parser)),
+    )
+    .arg(
+        Arg::new(clear.clone())
+            .long(clear)
+            .action(ArgAction::SetTrue)
+            .conflicts_with(name),
+    )
+}
+
+/// Reads the option and flag added by [`augment_args`] as a `Possible`.
+///
+/// The flag `--no-{name}` or an empty value read as `Possible::None`, and leaving out both
+/// reads as `Possible::Void`.
+///
+/// # Panics
+///
+/// Panics if `name` was not added with [`augment_args`] for values of type `T`.
+pub fn from_arg_matches<T>(matches: &ArgMatches, name: &str) -> Possible<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let value = matches.get_one::<Possible<T>>(name).cloned();
+    from_flags(value, matches.get_flag(&format!("no-{}", name)))
+}
+
+/// Combines an optional `value` read with [`ClearableParser`] and a `clear` flag into a
+/// `Possible`.
+///
+/// # Examples
+///
+/// ```
+/// use possible::Possible;
+///
+/// assert_eq!(possible::cli::from_flags(Some(Possible::Some(1)), false), Possible::Some(1));
+/// assert_eq!(possible::cli::from_flags::<i32>(None, true), Possible::None);
+/// assert_eq!(possible::cli::from_flags::<i32>(None, false), Possible::Void);
+/// ```
+pub fn from_flags<T>(value: Option<Possible<T>>, clear: bool) -> Possible<T> {
+    match value {
+        _ if clear => Possible::None,
+        Some(value) => value,
+        None => Possible::Void,
+    }
+}
+
+/// The name of the option of a [`ClearableArg`], such as `description` for
+/// `--description <VALUE>` and `--no-description`.
+pub trait ArgName {
+    /// The name of the option, which is also the id of its argument.
+    const NAME: &'static str;
+}
+
+/// An option `--{name} <VALUE>` and its flag `--no-{name}` read as a `Possible`, where the
+/// name comes from the [`ArgName`] of the marker type `N`.
+///
+/// This is the pair added by [`augment_args`] as a field of a `#[derive(Parser)]` or
+/// `#[derive(Args)]` struct, with `#[command(flatten)]`. Values are read with the
+/// [`FromStr`] implementation of `T`.
+///
+/// # Examples
+///
+/// ```
+/// use clap::Parser;
+/// use possible::{
+///     cli::{ArgName, ClearableArg},
+///     Possible,
+/// };
+///
+/// struct Description;
+///
+/// impl ArgName for Description {
+///     const NAME: &'static str = "description";
+/// }
+///
+/// #[derive(Parser)]
+/// struct Update {
+///     #[command(flatten)]
+///     description: ClearableArg<String, Description>,
+/// }
+///
+/// let args = Update::parse_from(["update", "--description", "Crab"]);
+/// assert_eq!(args.description.into_inner(), Possible::Some(String::from("Crab")));
+///
+/// let args = Update::parse_from(["update", "--no-description"]);
+/// assert_eq!(args.description.get(), &Possible::None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClearableArg<T, N> {
+    value: Possible<T>,
+    marker: PhantomData<fn() -> N>,
+}
+
+impl<T, N> ClearableArg<T, N> {
+    /// Creates an argument that was read as `value`.
+    pub fn new(value: Possible<T>) -> Self {
+        ClearableArg {
+            value,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the value that was read.
+    pub fn get(&self) -> &Possible<T> {
+        &self.value
+    }
+
+    /// Returns the value that was read.
+    pub fn into_inner(self) -> Possible<T> {
+        self.value
+    }
+}
+
+impl<T, N> Default for ClearableArg<T, N> {
+    fn default() -> Self {
+        ClearableArg::new(Possible::Void)
+    }
+}
+
+impl<T, N> From<ClearableArg<T, N>> for Possible<T> {
+    fn from(arg: ClearableArg<T, N>) -> Self {
+        arg.value
+    }
+}
+
+impl<T, N> FromArgMatches for ClearableArg<T, N>
+where
+    T: Clone + Send + Sync + 'static,
+    N: ArgName,
+{
+    fn from_arg_matches(matches: &ArgMatches) -> Result<Self, Error> {
+        Ok(ClearableArg::new(from_arg_matches(matches, N::NAME)))
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &ArgMatches) -> Result<(), Error> {
+        let value = core::mem::take(&mut self.value);
+        self.value = value.merge(from_arg_matches(matches, N::NAME));
+        Ok(())
+    }
+}
+
+impl<T, N> Args for ClearableArg<T, N>
+where
+    T: FromStr + Clone + Send + Sync + 'static,
+    T::Err: Into<Box<dyn StdError + Send + Sync + 'static>>,
+    N: ArgName,
+{
+    fn augment_args(cmd: Command) -> Command {
+        augment_args(cmd, N::NAME, parse::<T>)
+    }
+
+    fn augment_args_for_update(cmd: Command) -> Command {
+        augment_args(cmd, N::NAME, parse::<T>)
+    }
+}
+
+fn parse<T: FromStr>(value: &str) -> Result<T, T::Err> {
+    value.parse()
+}
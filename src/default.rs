@@ -1,5 +1,6 @@
 use super::Possible;
 
+#[allow(clippy::derivable_impls)]
 impl<T> Default for Possible<T> {
     fn default() -> Possible<T> {
         Possible::Void
@@ -0,0 +1,294 @@
+//! Environment variables as `Possible` values, behind the `std` feature.
+//!
+//! An environment variable is either unset, set to an empty value, or set to a value,
+//! which are read as `Possible::Void`, `Possible::None`, and `Possible::Some` with
+//! [`Possible::from_env`]. Values are parsed with `FromStr`.
+//!
+//! The [`FromEnv`] trait reads a struct of `Possible` fields from the environment, and is
+//! implemented with `#[derive(FromEnv)]` with the `derive` feature enabled. Errors of
+//! every field are collected into [`EnvErrors`] rather than stopping at the first one.
+//!
+//! Variables are read from a [`Source`], which is the environment of the process with
+//! [`System`], or a map of names to values such as a `HashMap` in tests.
+//!
+//! # Examples
+//!
+//! ```
+//! use possible::Possible;
+//! use std::collections::HashMap;
+//!
+//! let env = HashMap::from([("PORT", "8080"), ("PROXY", "")]);
+//!
+//! assert_eq!(Possible::from_env_source(&env, "PORT"), Ok(Possible::Some(8080)));
+//! assert_eq!(Possible::<String>::from_env_source(&env, "PROXY"), Ok(Possible::None));
+//! assert_eq!(Possible::<String>::from_env_source(&env, "HOST"), Ok(Possible::Void));
+//! ```
+
+use crate::Possible;
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    ffi::{OsStr, OsString},
+    fmt,
+    hash::{BuildHasher, Hash},
+    str::FromStr,
+    vec,
+};
+
+#[cfg(feature = "derive")]
+pub use possible_derive::FromEnv;
+
+/// A set of environment variables.
+pub trait Source {
+    /// Returns the value of the variable called `name`, or `None` if it is unset.
+    fn var_os(&self, name: &str) -> Option<OsString>;
+}
+
+/// The environment variables of the current process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct System;
+
+impl Source for System {
+    fn var_os(&self, name: &str) -> Option<OsString> {
+        std::env::var_os(name)
+    }
+}
+
+impl<T: Source + ?Sized> Source for &T {
+    fn var_os(&self, name: &str) -> Option<OsString> {
+        (**self).var_os(name)
+    }
+}
+
+impl<K, V, S> Source for HashMap<K, V, S>
+where
+    K: Borrow<str> + Hash + Eq,
+    V: AsRef<OsStr>,
+    S: BuildHasher,
+{
+    fn var_os(&self, name: &str) -> Option<OsString> {
+        self.get(name).map(|value| value.as_ref().to_os_string())
+    }
+}
+
+impl<K, V> Source for BTreeMap<K, V>
+where
+    K: Borrow<str> + Ord,
+    V: AsRef<OsStr>,
+{
+    fn var_os(&self, name: &str) -> Option<OsString> {
+        self.get(name).map(|value| value.as_ref().to_os_string())
+    }
+}
+
+impl<T> Possible<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    /// Reads the environment variable called `name` of the current process.
+    ///
+    /// An unset variable is `Possible::Void`, an empty variable is `Possible::None`, and any
+    /// other value is parsed as `Possible::Some`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the value is not valid unicode or cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::Possible;
+    ///
+    /// let value = Possible::<u16>::from_env("POSSIBLE_EXAMPLE_UNSET_PORT");
+    /// assert_eq!(value, Ok(Possible::Void));
+    /// ```
+    pub fn from_env(name: &str) -> Result<Self, EnvError> {
+        Self::from_env_source(&System, name)
+    }
+
+    /// Reads the environment variable called `name` from `source`, in the same way as
+    /// [`from_env`](Possible::from_env).
+    ///
+    /// # Errors
+    ///
+    /// Fails if the value is not valid unicode or cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::Possible;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let env = BTreeMap::from([("PORT", "http")]);
+    ///
+    /// let error = Possible::<u16>::from_env_source(&env, "PORT").unwrap_err();
+    /// assert_eq!(error.to_string(), "environment variable `PORT` is invalid: invalid digit found in string");
+    /// ```
+    pub fn from_env_source<S: Source + ?Sized>(source: &S, name: &str) -> Result<Self, EnvError> {
+        let value = match source.var_os(name) {
+            Some(value) => value,
+            None => return Ok(Possible::Void),
+        };
+
+        let value = value
+            .into_string()
+            .map_err(|_| EnvError::NotUnicode(name.to_string()))?;
+
+        if value.is_empty() {
+            return Ok(Possible::None);
+        }
+
+        value
+            .parse()
+            .map(Possible::Some)
+            .map_err(|error: T::Err| EnvError::Parse(name.to_string(), error.to_string()))
+    }
+}
+
+/// Reads a value from environment variables.
+///
+/// This is usually implemented with `#[derive(FromEnv)]` for a struct of `Possible` fields,
+/// where every field is read from the variable named after the field in upper case,
+/// following the prefix of the struct.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use possible::{env::FromEnv, Possible};
+/// use std::collections::HashMap;
+///
+/// #[derive(Debug, PartialEq, FromEnv)]
+/// #[env(prefix = "APP_")]
+/// struct Config {
+///     port: Possible<u16>,
+///     proxy: Possible<String>,
+///     #[env(rename = "LOG")]
+///     log_level: Possible<String>,
+/// }
+///
+/// let env = HashMap::from([("APP_PORT", "8080"), ("APP_PROXY", "")]);
+/// assert_eq!(
+///     Config::from_env_source(&env),
+///     Ok(Config {
+///         port: Possible::Some(8080),
+///         proxy: Possible::None,
+///         log_level: Possible::Void,
+///     }),
+/// );
+/// # }
+/// ```
+pub trait FromEnv: Sized {
+    /// Reads the value from the variables of `source`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with the errors of every variable that cannot be read.
+    fn from_env_source<S: Source + ?Sized>(source: &S) -> Result<Self, EnvErrors>;
+
+    /// Reads the value from the environment variables of the current process.
+    ///
+    /// # Errors
+    ///
+    /// Fails with the errors of every variable that cannot be read.
+    fn from_env() -> Result<Self, EnvErrors> {
+        Self::from_env_source(&System)
+    }
+}
+
+/// Error returned when an environment variable cannot be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvError {
+    /// The value of the named variable is not valid unicode.
+    NotUnicode(String),
+    /// The value of the named variable cannot be parsed, with the message of the parse error.
+    Parse(String, String),
+}
+
+impl EnvError {
+    /// Returns the name of the variable.
+    pub fn name(&self) -> &str {
+        match self {
+            EnvError::NotUnicode(name) | EnvError::Parse(name, _) => name,
+        }
+    }
+}
+
+impl fmt::Display for EnvError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EnvError::NotUnicode(name) => write!(
+                formatter,
+                "environment variable `{}` is not valid unicode",
+                name
+            ),
+            EnvError::Parse(name, message) => write!(
+                formatter,
+                "environment variable `{}` is invalid: {}",
+                name, message
+            ),
+        }
+    }
+}
+
+impl Error for EnvError {}
+
+/// The errors of every variable that could not be read by [`FromEnv`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EnvErrors {
+    errors: Vec<EnvError>,
+}
+
+impl EnvErrors {
+    /// Creates an empty list of errors.
+    pub fn new() -> Self {
+        EnvErrors::default()
+    }
+
+    /// Adds the error of a variable.
+    pub fn push(&mut self, error: EnvError) {
+        self.errors.push(error);
+    }
+
+    /// Returns the number of errors.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns whether there are no errors.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Iterates over the errors in the order of the fields.
+    pub fn iter(&self) -> impl Iterator<Item = &EnvError> {
+        self.errors.iter()
+    }
+}
+
+impl IntoIterator for EnvErrors {
+    type Item = EnvError;
+    type IntoIter = vec::IntoIter<EnvError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl fmt::Display for EnvErrors {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        for (index, error) in self.errors.iter().enumerate() {
+            if index > 0 {
+                formatter.write_str("; ")?;
+            }
+            write!(formatter, "{}", error)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for EnvErrors {}
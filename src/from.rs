@@ -1,5 +1,5 @@
 use super::Possible;
-use core::iter::FromIterator;
+use core::iter::{FromIterator, Product, Sum};
 
 impl<T> From<Possible<T>> for Option<T> {
     /// Copies `value` into an `Option::Some`.
@@ -108,21 +108,182 @@ impl<'a, T> From<&'a mut Possible<T>> for Possible<&'a mut T> {
     }
 }
 
+/// Which state results from collecting an iterator of `Possible` values that are not all
+/// `Possible::Some`, used by [`Possible::from_iter_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Precedence {
+    /// The first value that is `Possible::None` or `Possible::Void` is the result, and the
+    /// rest of the iterator is left unread.
+    #[default]
+    FirstWins,
+    /// Any `Possible::Void` value makes the result `Possible::Void`, otherwise any
+    /// `Possible::None` value makes it `Possible::None`.
+    VoidDominates,
+    /// Any `Possible::None` value makes the result `Possible::None`, otherwise any
+    /// `Possible::Void` value makes it `Possible::Void`.
+    NoneDominates,
+}
+
+impl<V> Possible<V> {
+    /// Collects an iterator of `Possible` values into a `Possible` of a collection.
+    ///
+    /// If every value is `Possible::Some`, the result is `Possible::Some` of the collected
+    /// values. Otherwise the result is `Possible::None` or `Possible::Void` as picked by
+    /// `precedence`. Collecting with `FromIterator` uses [`Precedence::FirstWins`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::{Possible, Precedence};
+    ///
+    /// let values = [Possible::Some(1), Possible::None, Possible::Void];
+    ///
+    /// let first: Possible<Vec<u8>> = Possible::from_iter_with(values, Precedence::FirstWins);
+    /// assert_eq!(first, Possible::None);
+    ///
+    /// let void: Possible<Vec<u8>> = Possible::from_iter_with(values, Precedence::VoidDominates);
+    /// assert_eq!(void, Possible::Void);
+    ///
+    /// let none: Possible<Vec<u8>> = Possible::from_iter_with(values, Precedence::NoneDominates);
+    /// assert_eq!(none, Possible::None);
+    ///
+    /// let all: Possible<Vec<u8>> = Possible::from_iter_with([Possible::Some(1)], Precedence::FirstWins);
+    /// assert_eq!(all, Possible::Some(vec![1]));
+    /// ```
+    pub fn from_iter_with<A, I>(iter: I, precedence: Precedence) -> Possible<V>
+    where
+        I: IntoIterator<Item = Possible<A>>,
+        V: FromIterator<A>,
+    {
+        try_process(iter.into_iter(), precedence, |shunt| shunt.collect())
+    }
+}
+
 impl<A, V: FromIterator<A>> FromIterator<Possible<A>> for Possible<V> {
+    /// Collects an iterator of `Possible` values into a `Possible` of a collection.
+    ///
+    /// If every value is `Possible::Some`, the result is `Possible::Some` of the collected
+    /// values. Otherwise the first value that is `Possible::None` or `Possible::Void` is the
+    /// result, so that a `Possible::Void` is not reported as a null that never existed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::Possible;
+    ///
+    /// let values = vec![Possible::Some(1), Possible::Some(2)];
+    /// let collected: Possible<Vec<u8>> = values.into_iter().collect();
+    /// assert_eq!(collected, Possible::Some(vec![1, 2]));
+    ///
+    /// let values = vec![Possible::Some(1), Possible::Void, Possible::None];
+    /// let collected: Possible<Vec<u8>> = values.into_iter().collect();
+    /// assert_eq!(collected, Possible::Void);
+    /// ```
     #[inline]
     fn from_iter<I: IntoIterator<Item = Possible<A>>>(iter: I) -> Possible<V> {
-        match iter
-            .into_iter()
-            .map(|x| x.ok_or(()))
-            .collect::<Result<_, _>>()
-            .ok()
-        {
-            Some(v) => Possible::Some(v),
-            None => Possible::None,
+        Possible::from_iter_with(iter, Precedence::FirstWins)
+    }
+}
+
+impl<T, U> Sum<Possible<U>> for Possible<T>
+where
+    T: Sum<U>,
+{
+    /// Sums an iterator of `Possible` values.
+    ///
+    /// If every value is `Possible::Some`, the result is `Possible::Some` of the sum.
+    /// Otherwise the first value that is `Possible::None` or `Possible::Void` is the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::Possible;
+    ///
+    /// let sum: Possible<u32> = [Possible::Some(1), Possible::Some(2)].iter().copied().sum();
+    /// assert_eq!(sum, Possible::Some(3));
+    ///
+    /// let sum: Possible<u32> = [Possible::Some(1), Possible::Void].iter().copied().sum();
+    /// assert_eq!(sum, Possible::Void);
+    /// ```
+    fn sum<I: Iterator<Item = Possible<U>>>(iter: I) -> Possible<T> {
+        try_process(iter, Precedence::FirstWins, |shunt| shunt.sum())
+    }
+}
+
+impl<T, U> Product<Possible<U>> for Possible<T>
+where
+    T: Product<U>,
+{
+    /// Multiplies an iterator of `Possible` values.
+    ///
+    /// If every value is `Possible::Some`, the result is `Possible::Some` of the product.
+    /// Otherwise the first value that is `Possible::None` or `Possible::Void` is the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::Possible;
+    ///
+    /// let product: Possible<u32> = [Possible::Some(2), Possible::Some(3)].iter().copied().product();
+    /// assert_eq!(product, Possible::Some(6));
+    ///
+    /// let product: Possible<u32> = [Possible::None, Possible::Some(3)].iter().copied().product();
+    /// assert_eq!(product, Possible::None);
+    /// ```
+    fn product<I: Iterator<Item = Possible<U>>>(iter: I) -> Possible<T> {
+        try_process(iter, Precedence::FirstWins, |shunt| shunt.product())
+    }
+}
+
+// yields the contained values until the first value that is not `Possible::Some`,
+// which is recorded in `state`
+struct Shunt<'a, I> {
+    iter: &'a mut I,
+    state: &'a mut Possible<()>,
+}
+
+impl<A, I: Iterator<Item = Possible<A>>> Iterator for Shunt<'_, I> {
+    type Item = A;
+
+    fn next(&mut self) -> Option<A> {
+        if !self.state.is_some() {
+            return None;
+        }
+
+        match self.iter.next()? {
+            Possible::Some(value) => Some(value),
+            Possible::None => {
+                *self.state = Possible::None;
+                None
+            }
+            Possible::Void => {
+                *self.state = Possible::Void;
+                None
+            }
         }
     }
 }
 
+fn try_process<A, I, U, F>(mut iter: I, precedence: Precedence, f: F) -> Possible<U>
+where
+    I: Iterator<Item = Possible<A>>,
+    F: FnOnce(Shunt<'_, I>) -> U,
+{
+    let mut state = Possible::Some(());
+    let value = f(Shunt {
+        iter: &mut iter,
+        state: &mut state,
+    });
+
+    match (state, precedence) {
+        (Possible::Some(()), _) => Possible::Some(value),
+        (Possible::None, Precedence::VoidDominates) if iter.any(|x| x.is_void()) => Possible::Void,
+        (Possible::Void, Precedence::NoneDominates) if iter.any(|x| x.is_none()) => Possible::None,
+        (Possible::None, _) => Possible::None,
+        (Possible::Void, _) => Possible::Void,
+    }
+}
+
 impl<A, V: FromIterator<A>> FromIterator<Option<A>> for Possible<V> {
     #[inline]
     fn from_iter<I: IntoIterator<Item = Option<A>>>(iter: I) -> Possible<V> {
@@ -0,0 +1,11 @@
+//! GraphQL support for `Possible`, behind the `async-graphql` and `juniper` features.
+//!
+//! An argument or input object field of type `Possible<T>` is nullable, and parses as
+//! `Possible::Void` when it is left out and as `Possible::None` when it is an explicit
+//! `null`. GraphQL responses cannot leave out a selected field, so `Possible::Void` is
+//! resolved as `null` the same way as `Possible::None`.
+
+#[cfg(feature = "async-graphql")]
+mod async_graphql;
+#[cfg(feature = "juniper")]
+mod juniper;
@@ -0,0 +1,123 @@
+use crate::Possible;
+use async_graphql::{
+    parser::types::Field, registry::Registry, ContextSelectionSet, InputType, InputValueError,
+    InputValueResult, MaybeUndefined, OutputType, Positioned, ServerResult, Value,
+};
+use std::borrow::Cow;
+
+impl<T: InputType> InputType for Possible<T> {
+    type RawValueType = T::RawValueType;
+
+    fn type_name() -> Cow<'static, str> {
+        T::type_name()
+    }
+
+    fn qualified_type_name() -> String {
+        T::type_name().to_string()
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        T::create_type_info(registry);
+        T::type_name().to_string()
+    }
+
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        match value {
+            None => Ok(Possible::Void),
+            Some(Value::Null) => Ok(Possible::None),
+            Some(value) => Ok(Possible::Some(
+                T::parse(Some(value)).map_err(InputValueError::propagate)?,
+            )),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        match self {
+            Possible::Some(value) => value.to_value(),
+            Possible::None | Possible::Void => Value::Null,
+        }
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        match self {
+            Possible::Some(value) => value.as_raw_value(),
+            Possible::None | Possible::Void => None,
+        }
+    }
+}
+
+impl<T: OutputType + Sync> OutputType for Possible<T> {
+    fn type_name() -> Cow<'static, str> {
+        T::type_name()
+    }
+
+    fn qualified_type_name() -> String {
+        T::type_name().to_string()
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        T::create_type_info(registry);
+        T::type_name().to_string()
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        match self {
+            Possible::Some(value) => match OutputType::resolve(value, ctx, field).await {
+                Ok(value) => Ok(value),
+                Err(error) => {
+                    ctx.add_error(error);
+                    Ok(Value::Null)
+                }
+            },
+            Possible::None | Possible::Void => Ok(Value::Null),
+        }
+    }
+}
+
+impl<T> From<MaybeUndefined<T>> for Possible<T> {
+    /// Converts `MaybeUndefined::Undefined` to `Possible::Void` and
+    /// `MaybeUndefined::Null` to `Possible::None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_graphql::MaybeUndefined;
+    /// use possible::Possible;
+    ///
+    /// let value: Possible<i32> = MaybeUndefined::Undefined.into();
+    /// assert_eq!(value, Possible::Void);
+    /// ```
+    fn from(value: MaybeUndefined<T>) -> Self {
+        match value {
+            MaybeUndefined::Value(value) => Possible::Some(value),
+            MaybeUndefined::Null => Possible::None,
+            MaybeUndefined::Undefined => Possible::Void,
+        }
+    }
+}
+
+impl<T> From<Possible<T>> for MaybeUndefined<T> {
+    /// Converts `Possible::Void` to `MaybeUndefined::Undefined` and `Possible::None` to
+    /// `MaybeUndefined::Null`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_graphql::MaybeUndefined;
+    /// use possible::Possible;
+    ///
+    /// let value: MaybeUndefined<i32> = Possible::None.into();
+    /// assert_eq!(value, MaybeUndefined::Null);
+    /// ```
+    fn from(value: Possible<T>) -> Self {
+        match value {
+            Possible::Some(value) => MaybeUndefined::Value(value),
+            Possible::None => MaybeUndefined::Null,
+            Possible::Void => MaybeUndefined::Undefined,
+        }
+    }
+}
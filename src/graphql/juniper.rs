@@ -0,0 +1,180 @@
+use crate::Possible;
+use juniper::{
+    macros::reflect::{BaseSubTypes, BaseType, Type, Types, WrappedType},
+    marker::{IsInputType, IsOutputType},
+    meta::MetaType,
+    BoxFuture, ExecutionResult, Executor, FromInputValue, GraphQLType, GraphQLValue,
+    GraphQLValueAsync, InputValue, Nullable, Registry, ScalarValue, Selection, ToInputValue, Value,
+};
+
+impl<S, T> GraphQLType<S> for Possible<T>
+where
+    T: GraphQLType<S>,
+    S: ScalarValue,
+{
+    fn name(_: &Self::TypeInfo) -> Option<&'static str> {
+        None
+    }
+
+    fn meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        registry.build_nullable_type::<T>(info).into_meta()
+    }
+}
+
+impl<S, T> GraphQLValue<S> for Possible<T>
+where
+    T: GraphQLValue<S>,
+    S: ScalarValue,
+{
+    type Context = T::Context;
+    type TypeInfo = T::TypeInfo;
+
+    fn type_name(&self, _: &Self::TypeInfo) -> Option<&'static str> {
+        None
+    }
+
+    fn resolve(
+        &self,
+        info: &Self::TypeInfo,
+        _: Option<&[Selection<S>]>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        match self {
+            Possible::Some(value) => executor.resolve(info, value),
+            Possible::None | Possible::Void => Ok(Value::null()),
+        }
+    }
+}
+
+impl<S, T> GraphQLValueAsync<S> for Possible<T>
+where
+    T: GraphQLValueAsync<S>,
+    T::TypeInfo: Sync,
+    T::Context: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        _: Option<&'a [Selection<S>]>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        Box::pin(async move {
+            match self {
+                Possible::Some(value) => Ok(executor.resolve_into_value_async(info, value).await),
+                Possible::None | Possible::Void => Ok(Value::null()),
+            }
+        })
+    }
+}
+
+impl<S, T: FromInputValue<S>> FromInputValue<S> for Possible<T> {
+    type Error = T::Error;
+
+    fn from_input_value(value: &InputValue<S>) -> Result<Self, Self::Error> {
+        match value {
+            InputValue::Null => Ok(Possible::None),
+            value => value.convert().map(Possible::Some),
+        }
+    }
+
+    fn from_implicit_null() -> Result<Self, Self::Error> {
+        Ok(Possible::Void)
+    }
+}
+
+impl<S, T> ToInputValue<S> for Possible<T>
+where
+    T: ToInputValue<S>,
+    S: ScalarValue,
+{
+    fn to_input_value(&self) -> InputValue<S> {
+        match self {
+            Possible::Some(value) => value.to_input_value(),
+            Possible::None | Possible::Void => InputValue::null(),
+        }
+    }
+}
+
+impl<S, T> IsInputType<S> for Possible<T>
+where
+    T: IsInputType<S>,
+    S: ScalarValue,
+{
+    #[inline]
+    fn mark() {
+        T::mark()
+    }
+}
+
+impl<S, T> IsOutputType<S> for Possible<T>
+where
+    T: IsOutputType<S>,
+    S: ScalarValue,
+{
+    #[inline]
+    fn mark() {
+        T::mark()
+    }
+}
+
+// used by the assertions generated from juniper's object and interface macros
+
+impl<S, T: BaseType<S>> BaseType<S> for Possible<T> {
+    const NAME: Type = T::NAME;
+}
+
+impl<S, T: BaseSubTypes<S>> BaseSubTypes<S> for Possible<T> {
+    const NAMES: Types = T::NAMES;
+}
+
+impl<S, T: WrappedType<S>> WrappedType<S> for Possible<T> {
+    const VALUE: u128 = T::VALUE * 10 + 2;
+}
+
+impl<T> From<Nullable<T>> for Possible<T> {
+    /// Converts `Nullable::ImplicitNull` to `Possible::Void` and `Nullable::ExplicitNull`
+    /// to `Possible::None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use juniper::Nullable;
+    /// use possible::Possible;
+    ///
+    /// let value: Possible<i32> = Nullable::ImplicitNull.into();
+    /// assert_eq!(value, Possible::Void);
+    /// ```
+    fn from(value: Nullable<T>) -> Self {
+        match value {
+            Nullable::Some(value) => Possible::Some(value),
+            Nullable::ExplicitNull => Possible::None,
+            Nullable::ImplicitNull => Possible::Void,
+        }
+    }
+}
+
+impl<T> From<Possible<T>> for Nullable<T> {
+    /// Converts `Possible::Void` to `Nullable::ImplicitNull` and `Possible::None` to
+    /// `Nullable::ExplicitNull`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use juniper::Nullable;
+    /// use possible::Possible;
+    ///
+    /// let value: Nullable<i32> = Possible::None.into();
+    /// assert_eq!(value, Nullable::ExplicitNull);
+    /// ```
+    fn from(value: Possible<T>) -> Self {
+        match value {
+            Possible::Some(value) => Nullable::Some(value),
+            Possible::None => Nullable::ExplicitNull,
+            Possible::Void => Nullable::ImplicitNull,
+        }
+    }
+}
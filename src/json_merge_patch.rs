@@ -0,0 +1,186 @@
+//! [RFC 7396](https://tools.ietf.org/html/rfc7396) JSON Merge Patch support.
+//!
+//! A merge patch describes changes to a JSON document using the same distinction that
+//! `Possible` captures: members that are absent from the patch are left unchanged
+//! ([`Possible::Void`]), members set to `null` are removed ([`Possible::None`]), and any
+//! other member replaces or recursively patches the target ([`Possible::Some`]).
+//!
+//! Besides working on [`Value`]s, patches can be computed from and applied to any
+//! serializable type, such as a struct of `Possible` fields.
+//!
+//! # Examples
+//!
+//! ```
+//! use possible::{json_merge_patch, Possible};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize, PartialEq)]
+//! struct User {
+//!     name: String,
+//!     email: Option<String>,
+//! }
+//!
+//! #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+//! struct UserPatch {
+//!     #[serde(default, skip_serializing_if = "Possible::is_void")]
+//!     name: Possible<String>,
+//!     #[serde(default, skip_serializing_if = "Possible::is_void")]
+//!     email: Possible<String>,
+//! }
+//!
+//! let mut user = User {
+//!     name: String::from("Ferris"),
+//!     email: Some(String::from("ferris@example.com")),
+//! };
+//! let updated = User {
+//!     name: String::from("Ferris"),
+//!     email: None,
+//! };
+//!
+//! let patch: UserPatch = json_merge_patch::diff_to(&user, &updated).unwrap();
+//! assert_eq!(patch, UserPatch {
+//!     name: Possible::Void,
+//!     email: Possible::None,
+//! });
+//!
+//! json_merge_patch::apply_to(&mut user, &patch).unwrap();
+//! assert_eq!(user, updated);
+//! ```
+
+use super::Possible;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Error, Map};
+
+pub use serde_json::Value;
+
+/// Applies the merge `patch` to `target` in place.
+///
+/// This is the `MergePatch` algorithm described in section 2 of RFC 7396.
+///
+/// # Examples
+///
+/// ```
+/// use possible::json_merge_patch;
+/// use serde_json::json;
+///
+/// let mut target = json!({ "title": "Goodbye!", "author": { "givenName": "John" } });
+/// let patch = json!({ "title": "Hello!", "author": { "familyName": null } });
+///
+/// json_merge_patch::apply(&mut target, patch);
+/// assert_eq!(target, json!({ "title": "Hello!", "author": { "givenName": "John" } }));
+/// ```
+pub fn apply(target: &mut Value, patch: Value) {
+    let members = match patch {
+        Value::Object(members) => members,
+        patch => {
+            *target = patch;
+            return;
+        }
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+
+    if let Value::Object(object) = target {
+        for (name, value) in members {
+            match member(value) {
+                Possible::Some(value) => apply(object.entry(name).or_insert(Value::Null), value),
+                Possible::None | Possible::Void => {
+                    object.remove(&name);
+                }
+            }
+        }
+    }
+}
+
+/// Computes the merge patch that turns `source` into `target`.
+///
+/// Applying the result to `source` with [`apply`] produces `target`, with the exception of
+/// `null` members of objects in `target`, which merge patches cannot express.
+///
+/// # Examples
+///
+/// ```
+/// use possible::json_merge_patch;
+/// use serde_json::json;
+///
+/// let source = json!({ "a": "b", "c": { "d": "e", "f": "g" } });
+/// let target = json!({ "a": "z", "c": { "d": "e" } });
+///
+/// let patch = json_merge_patch::diff(&source, &target);
+/// assert_eq!(patch, json!({ "a": "z", "c": { "f": null } }));
+/// ```
+pub fn diff(source: &Value, target: &Value) -> Value {
+    let (source, target) = match (source, target) {
+        (Value::Object(source), Value::Object(target)) => (source, target),
+        (_, target) => return target.clone(),
+    };
+
+    let mut patch = Map::new();
+
+    for name in source.keys() {
+        if !target.contains_key(name) {
+            patch.insert(name.clone(), Value::Null);
+        }
+    }
+
+    for (name, value) in target {
+        match source.get(name) {
+            Some(previous) if previous == value => {}
+            Some(previous) => {
+                patch.insert(name.clone(), diff(previous, value));
+            }
+            None => {
+                patch.insert(name.clone(), value.clone());
+            }
+        }
+    }
+
+    Value::Object(patch)
+}
+
+/// Computes the merge patch that turns `source` into `target` as a patch type `P`,
+/// typically a struct of `Possible` fields.
+///
+/// # Errors
+///
+/// Fails if either value cannot be serialized to JSON, or if the patch cannot be
+/// deserialized as `P`.
+pub fn diff_to<T, P>(source: &T, target: &T) -> Result<P, Error>
+where
+    T: Serialize,
+    P: DeserializeOwned,
+{
+    let source = serde_json::to_value(source)?;
+    let target = serde_json::to_value(target)?;
+
+    serde_json::from_value(diff(&source, &target))
+}
+
+/// Applies a merge `patch` of any serializable type, typically a struct of `Possible`
+/// fields, to `target` in place.
+///
+/// # Errors
+///
+/// Fails if either value cannot be serialized to JSON, or if the patched document cannot
+/// be deserialized as `T`, in which case `target` is left unchanged.
+pub fn apply_to<T, P>(target: &mut T, patch: &P) -> Result<(), Error>
+where
+    T: Serialize + DeserializeOwned,
+    P: Serialize,
+{
+    let mut document = serde_json::to_value(&*target)?;
+    apply(&mut document, serde_json::to_value(patch)?);
+
+    *target = serde_json::from_value(document)?;
+    Ok(())
+}
+
+/// Reads a member of a merge patch object, where `null` removes the member.
+fn member(value: Value) -> Possible<Value> {
+    match value {
+        Value::Null => Possible::None,
+        value => Possible::Some(value),
+    }
+}
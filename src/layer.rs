@@ -0,0 +1,338 @@
+//! Layered configuration, where a stack of layers of `Possible` fields is folded into a
+//! resolved configuration.
+//!
+//! Each layer, such as defaults, a configuration file, the environment, or the command
+//! line, only mentions some of the fields. A `Possible::Void` field leaves the value of the
+//! earlier layers unchanged, a `Possible::None` field resets it to null, and a
+//! `Possible::Some` field overrides it. The [`Stack`] folds the layers in the order they are
+//! pushed and keeps the [`Provenance`] of every field, which is the layer that last set or
+//! cleared it.
+//!
+//! The [`Layered`] trait is implemented for a struct of `Possible` fields with
+//! `#[derive(Layered)]`, with the `derive` feature enabled. With the `figment` or `config`
+//! feature enabled, layers can also be read from the providers of
+//! [`figment`](https://docs.rs/figment) or the sources of [`config`](https://docs.rs/config)
+//! with [`Stack::push_provider`] and [`Stack::push_source`].
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use possible::{
+//!     layer::{Layered, Source, Stack},
+//!     Possible,
+//! };
+//!
+//! #[derive(Default, Layered)]
+//! #[layered(name = "Config", derive(Debug, PartialEq))]
+//! struct ConfigLayer {
+//!     #[layered(required)]
+//!     port: Possible<u16>,
+//!     proxy: Possible<String>,
+//! }
+//!
+//! let defaults = ConfigLayer {
+//!     port: Possible::Some(8080),
+//!     proxy: Possible::Some(String::from("http://proxy")),
+//! };
+//! let env = ConfigLayer {
+//!     port: Possible::Void,
+//!     proxy: Possible::None,
+//! };
+//!
+//! let resolved = Stack::new()
+//!     .push("defaults", defaults)
+//!     .push("env", env)
+//!     .resolve()
+//!     .unwrap();
+//!
+//! assert_eq!(resolved.value, Config { port: 8080, proxy: None });
+//! assert_eq!(resolved.provenance.get("port"), Some(&Source::Set(String::from("defaults"))));
+//! assert_eq!(resolved.provenance.get("proxy"), Some(&Source::Cleared(String::from("env"))));
+//! # }
+//! ```
+
+#[cfg(feature = "config")]
+mod config;
+#[cfg(feature = "figment")]
+mod figment;
+
+use crate::{Merge, Possible};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+#[cfg(feature = "derive")]
+pub use possible_derive::Layered;
+
+/// A layer of configuration that can be folded with other layers into a resolved value.
+///
+/// Layers are combined with [`Merge`], where a newer layer takes precedence over an older
+/// one unless its fields are `Possible::Void`, and the default value is a layer that sets
+/// nothing. This trait is usually implemented with `#[derive(Layered)]`, and layers are
+/// folded with a [`Stack`].
+///
+/// Fields are identified by their path from the root layer, such as `port` or
+/// `server.port`, and `prefix` is prepended to the names of the fields of a layer, such as
+/// `server.` for a layer nested in the `server` field of another layer.
+pub trait Layered: Default + Merge {
+    /// The configuration resolved from the layers.
+    type Output;
+
+    /// Records the fields that the layer called `layer` sets or clears in `provenance`.
+    fn record(&self, prefix: &str, layer: &str, provenance: &mut Provenance);
+
+    /// Resolves the merged layers, where `provenance` holds the layers that set each field.
+    ///
+    /// # Errors
+    ///
+    /// Fails if a required field is not set by any layer or cleared by the last layer that
+    /// mentions it.
+    fn resolve(self, prefix: &str, provenance: &Provenance) -> Result<Self::Output, LayerError>;
+}
+
+/// The layer that last set or cleared a field.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Source {
+    /// The named layer set the field to a value.
+    Set(String),
+    /// The named layer cleared the field to null.
+    Cleared(String),
+}
+
+impl Source {
+    /// Returns the name of the layer.
+    pub fn layer(&self) -> &str {
+        match self {
+            Source::Set(layer) | Source::Cleared(layer) => layer,
+        }
+    }
+}
+
+/// The [`Source`] of every field that is set or cleared by a layer, by the path of the
+/// field.
+///
+/// # Examples
+///
+/// ```
+/// use possible::{
+///     layer::{Provenance, Source},
+///     Possible,
+/// };
+///
+/// let mut provenance = Provenance::new();
+/// provenance.record(String::from("port"), "defaults", &Possible::Some(8080));
+/// provenance.record(String::from("port"), "env", &Possible::<u16>::Void);
+/// provenance.record(String::from("proxy"), "env", &Possible::<String>::None);
+///
+/// assert_eq!(provenance.get("port"), Some(&Source::Set(String::from("defaults"))));
+/// assert_eq!(provenance.get("proxy"), Some(&Source::Cleared(String::from("env"))));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Provenance {
+    sources: BTreeMap<String, Source>,
+}
+
+impl Provenance {
+    /// Creates an empty provenance.
+    pub fn new() -> Self {
+        Provenance::default()
+    }
+
+    /// Returns the source of the field at `path`, or `None` if no layer mentions it.
+    pub fn get(&self, path: &str) -> Option<&Source> {
+        self.sources.get(path)
+    }
+
+    /// Iterates over the paths of the fields and their sources, ordered by path.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Source)> {
+        self.sources
+            .iter()
+            .map(|(path, source)| (path.as_str(), source))
+    }
+
+    /// Sets the source of the field at `path`.
+    ///
+    /// Since the field replaces any value it is part of or that is part of it, this also
+    /// removes the sources of the fields nested in it, such as `server.port` for `server`,
+    /// and of the fields it is nested in.
+    pub fn insert(&mut self, path: String, source: Source) {
+        self.sources
+            .retain(|other, _| !is_nested(other, &path) && !is_nested(&path, other));
+        self.sources.insert(path, source);
+    }
+
+    /// Records the source of the field at `path` for its `value` in the layer called
+    /// `layer`, which leaves the source unchanged when `value` is `Possible::Void`.
+    pub fn record<T>(&mut self, path: String, layer: &str, value: &Possible<T>) {
+        match value {
+            Possible::Some(_) => self.insert(path, Source::Set(layer.to_string())),
+            Possible::None => self.insert(path, Source::Cleared(layer.to_string())),
+            Possible::Void => {}
+        }
+    }
+}
+
+/// Whether the field at `path` is nested in the field at `parent`.
+fn is_nested(path: &str, parent: &str) -> bool {
+    matches!(path.strip_prefix(parent), Some(rest) if rest.starts_with('.'))
+}
+
+/// Resolves a required field at `path`, which must be set to a value.
+///
+/// # Errors
+///
+/// Fails if `value` is `Possible::Void` or `Possible::None`, naming the layer that cleared
+/// the field in the latter case.
+pub fn required<T>(
+    value: Possible<T>,
+    path: String,
+    provenance: &Provenance,
+) -> Result<T, LayerError> {
+    match value {
+        Possible::Some(value) => Ok(value),
+        Possible::None => {
+            let layer = provenance
+                .get(&path)
+                .map(|source| source.layer().to_string())
+                .unwrap_or_default();
+            Err(LayerError::Cleared(path, layer))
+        }
+        Possible::Void => Err(LayerError::Missing(path)),
+    }
+}
+
+/// Resolves a nested layer at `path`, where a `Possible::Void` layer resolves as the default
+/// layer.
+///
+/// # Errors
+///
+/// Fails if `value` is `Possible::None`, since a nested layer cannot be null, or if the
+/// nested layer fails to resolve.
+pub fn nested<L: Layered>(
+    value: Possible<L>,
+    path: String,
+    provenance: &Provenance,
+) -> Result<L::Output, LayerError> {
+    let prefix = path.clone() + ".";
+
+    match value {
+        Possible::Some(layer) => layer.resolve(&prefix, provenance),
+        Possible::Void => L::default().resolve(&prefix, provenance),
+        Possible::None => required(Possible::None, path, provenance),
+    }
+}
+
+/// Merges two nested layers, merging them recursively when both are `Possible::Some`.
+///
+/// A newer `Possible::Some` following a `Possible::None` starts over from the default layer
+/// rather than keeping the cleared one, so that a later layer can set a nested layer that an
+/// earlier layer cleared. Otherwise `newer` takes precedence unless it is `Possible::Void`.
+pub fn merge_nested<L: Layered>(older: Possible<L>, newer: Possible<L>) -> Possible<L> {
+    match (older, newer) {
+        (Possible::None, Possible::Some(newer)) => Possible::Some(L::default().merge(newer)),
+        (older, newer) => older.merge_nested(newer),
+    }
+}
+
+/// A stack of named layers, where later layers take precedence over earlier ones.
+#[derive(Debug, Clone)]
+pub struct Stack<L> {
+    layers: Vec<(String, L)>,
+}
+
+impl<L> Default for Stack<L> {
+    fn default() -> Self {
+        Stack { layers: Vec::new() }
+    }
+}
+
+impl<L> Stack<L> {
+    /// Creates an empty stack.
+    pub fn new() -> Self {
+        Stack::default()
+    }
+
+    /// Pushes a layer called `name`, which takes precedence over the layers before it.
+    pub fn push<N: Into<String>>(mut self, name: N, layer: L) -> Self {
+        self.layers.push((name.into(), layer));
+        self
+    }
+
+    /// Iterates over the names and layers of the stack, from the first layer to the last.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &L)> {
+        self.layers
+            .iter()
+            .map(|(name, layer)| (name.as_str(), layer))
+    }
+}
+
+impl<L: Layered> Stack<L> {
+    /// Folds the layers into a single layer, along with the provenance of its fields.
+    pub fn fold(self) -> (L, Provenance) {
+        let mut provenance = Provenance::new();
+        let mut merged = L::default();
+
+        for (name, layer) in self.layers {
+            layer.record("", &name, &mut provenance);
+            merged = merged.merge(layer);
+        }
+
+        (merged, provenance)
+    }
+
+    /// Folds the layers and resolves the result.
+    ///
+    /// # Errors
+    ///
+    /// Fails if a required field is not set by any layer or cleared by the last layer that
+    /// mentions it.
+    pub fn resolve(self) -> Result<Resolved<L::Output>, LayerError> {
+        let (merged, provenance) = self.fold();
+        let value = merged.resolve("", &provenance)?;
+
+        Ok(Resolved { value, provenance })
+    }
+}
+
+/// A configuration resolved by a [`Stack`], along with the provenance of its fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolved<T> {
+    /// The resolved configuration.
+    pub value: T,
+    /// The layers that set or cleared each field.
+    pub provenance: Provenance,
+}
+
+/// Error returned when a stack of layers cannot be resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayerError {
+    /// No layer sets the required field at the path.
+    Missing(String),
+    /// The required field at the path was cleared by the named layer.
+    Cleared(String, String),
+}
+
+impl fmt::Display for LayerError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LayerError::Missing(path) => write!(formatter, "field `{}` is not set", path),
+            LayerError::Cleared(path, layer) => {
+                write!(
+                    formatter,
+                    "field `{}` is cleared by layer `{}`",
+                    path, layer
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for LayerError {}
@@ -0,0 +1,55 @@
+use super::Stack;
+use config::{Config, ConfigError, Source};
+use serde::de::DeserializeOwned;
+
+impl<L: DeserializeOwned> Stack<L> {
+    /// Pushes a layer called `name` that is read from a `config` source on its own.
+    ///
+    /// Unlike adding sources to a single `Config`, every source is a separate layer, so that
+    /// a missing key is `Possible::Void` and a nil value is `Possible::None`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the source cannot be read or deserialized as a layer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "derive")]
+    /// # {
+    /// use config::{File, FileFormat};
+    /// use possible::{layer::{Layered, Stack}, Possible};
+    /// use serde::Deserialize;
+    ///
+    /// #[possible::fields]
+    /// #[derive(Default, Deserialize, Layered)]
+    /// #[layered(name = "Config", derive(Debug, PartialEq))]
+    /// struct ConfigLayer {
+    ///     #[layered(required)]
+    ///     port: Possible<u16>,
+    ///     proxy: Possible<String>,
+    /// }
+    ///
+    /// let resolved = Stack::<ConfigLayer>::new()
+    ///     .push_source("file", File::from_str("port = 8080\nproxy = 'http://proxy'", FileFormat::Toml))
+    ///     .unwrap()
+    ///     .push_source("overrides", File::from_str(r#"{ "proxy": null }"#, FileFormat::Json))
+    ///     .unwrap()
+    ///     .resolve()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(resolved.value, Config { port: 8080, proxy: None });
+    /// # }
+    /// ```
+    pub fn push_source<N, S>(self, name: N, source: S) -> Result<Self, ConfigError>
+    where
+        N: Into<String>,
+        S: Source + Send + Sync + 'static,
+    {
+        let layer = Config::builder()
+            .add_source(source)
+            .build()?
+            .try_deserialize()?;
+        Ok(self.push(name, layer))
+    }
+}
@@ -0,0 +1,56 @@
+use super::Stack;
+use figment::{Error, Figment, Provider};
+use serde::de::DeserializeOwned;
+
+impl<L: DeserializeOwned> Stack<L> {
+    /// Pushes a layer called `name` that is extracted from a `figment` provider on its own.
+    ///
+    /// Unlike merging providers into a single `Figment`, every provider is a separate layer,
+    /// so that a missing key is `Possible::Void` and an empty value is `Possible::None`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the provider cannot be read or extracted as a layer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "derive")]
+    /// # {
+    /// use figment::providers::{Format, Serialized, Toml};
+    /// use possible::{layer::{Layered, Stack}, Possible};
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[possible::fields]
+    /// #[derive(Default, Serialize, Deserialize, Layered)]
+    /// #[layered(name = "Config", derive(Debug, PartialEq))]
+    /// struct ConfigLayer {
+    ///     #[layered(required)]
+    ///     port: Possible<u16>,
+    ///     proxy: Possible<String>,
+    /// }
+    ///
+    /// let cli = ConfigLayer { port: Possible::Void, proxy: Possible::None };
+    ///
+    /// let resolved = Stack::<ConfigLayer>::new()
+    ///     .push_provider("file", Toml::string("port = 8080\nproxy = 'http://proxy'"))
+    ///     .unwrap()
+    ///     .push_provider("cli", Serialized::defaults(cli))
+    ///     .unwrap()
+    ///     .resolve()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(resolved.value, Config { port: 8080, proxy: None });
+    /// # }
+    /// ```
+    // the error is returned as is to match the rest of the `figment` API
+    #[allow(clippy::result_large_err)]
+    pub fn push_provider<N, P>(self, name: N, provider: P) -> Result<Self, Error>
+    where
+        N: Into<String>,
+        P: Provider,
+    {
+        let layer = Figment::from(provider).extract()?;
+        Ok(self.push(name, layer))
+    }
+}
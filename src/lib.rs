@@ -1,3 +1,8 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod boolean;
 mod copy;
 mod default;
@@ -7,13 +12,61 @@ mod from;
 mod introspection;
 mod iter;
 mod map;
+#[cfg(feature = "alloc")]
+mod patch;
 mod refs;
 mod replace;
-mod serde;
 mod transpose;
 mod unwrap;
 mod zip;
 
+#[cfg(feature = "serde")]
+pub mod repr;
+
+#[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+mod testing;
+
+#[cfg(feature = "clap")]
+pub mod cli;
+
+#[cfg(feature = "std")]
+pub mod env;
+
+#[cfg(any(feature = "async-graphql", feature = "juniper"))]
+mod graphql;
+
+#[cfg(feature = "json-merge-patch")]
+pub mod json_merge_patch;
+
+#[cfg(feature = "alloc")]
+pub mod layer;
+
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+mod schema;
+
+#[cfg(any(feature = "sqlx", feature = "diesel", feature = "sea-orm"))]
+pub mod sql;
+
+#[cfg(feature = "validator")]
+pub mod validate;
+
+pub use from::Precedence;
+
+#[cfg(feature = "alloc")]
+pub use patch::{Merge, Patch, PatchError, Patchable};
+
+#[cfg(feature = "derive")]
+pub use possible_derive::{fields, Patch};
+
+#[cfg(feature = "serde_with")]
+pub use crate::repr::PossibleAs;
+
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+pub use testing::Weights;
+
+#[cfg(feature = "proptest")]
+pub use testing::{strategy, PossibleStrategy, PossibleValueTree};
+
 /// Three state enum for differentiating between an explicit null value and the absense of a value
 #[derive(PartialEq, PartialOrd, Eq, Ord, Debug, Hash, Copy)]
 pub enum Possible<T> {
@@ -22,6 +75,17 @@ pub enum Possible<T> {
     Void,
 }
 
+// used by code generated from the derive macros
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::format;
+    #[cfg(feature = "diesel")]
+    pub use diesel;
+    #[cfg(feature = "serde")]
+    pub use serde;
+}
+
 // run doctests in project readme file
-#[cfg(doctest)]
+#[cfg(all(doctest, feature = "std", feature = "serde"))]
 doc_comment::doctest!("../README.md", readme);
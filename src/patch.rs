@@ -0,0 +1,549 @@
+use super::Possible;
+use alloc::{boxed::Box, collections::BTreeMap};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    error::Error,
+    hash::{BuildHasher, Hash},
+};
+
+/// Applies a partial update to a target value.
+///
+/// `Possible` implements this trait for both nullable (`Option<T>`) and non-nullable
+/// (`T`) targets, which gives a single meaning to applying a patch field:
+///
+/// - [`Possible::Void`] leaves the target unchanged.
+/// - [`Possible::None`] sets an `Option` target to `None`, and is an error for
+///   non-nullable targets.
+/// - [`Possible::Some`] overwrites the target with the contained value.
+///
+/// Since a `Possible<Option<T>>` could also patch an `Option<T>` as a plain value, the
+/// type of a `Possible::None` or `Possible::Void` may need to be spelled out when it is
+/// not otherwise known.
+///
+/// # Examples
+///
+/// ```
+/// use possible::{Patch, PatchError, Possible};
+///
+/// let mut nickname = Some("Ferris");
+/// Possible::<&str>::Void.apply(&mut nickname).unwrap();
+/// assert_eq!(nickname, Some("Ferris"));
+///
+/// Possible::<&str>::None.apply(&mut nickname).unwrap();
+/// assert_eq!(nickname, None);
+///
+/// let mut name = "Ferris";
+/// Possible::Some("Corro").apply(&mut name).unwrap();
+/// assert_eq!(name, "Corro");
+///
+/// let patch: Possible<&str> = Possible::None;
+/// assert_eq!(patch.apply(&mut name), Err(PatchError::NotNullable));
+/// assert_eq!(name, "Corro");
+/// ```
+pub trait Patch<Target: ?Sized> {
+    /// Applies the update to `target` in place.
+    fn apply(self, target: &mut Target) -> Result<(), PatchError>;
+
+    /// Checks that the update can be applied to `target`, without changing it.
+    ///
+    /// [`apply`](Patch::apply) fails exactly when this fails, so that a patch of a struct
+    /// can check every field before changing any of them.
+    fn check(&self, target: &Target) -> Result<(), PatchError>;
+}
+
+impl<T> Patch<Option<T>> for Possible<T> {
+    #[inline]
+    fn apply(self, target: &mut Option<T>) -> Result<(), PatchError> {
+        match self {
+            Possible::Some(value) => *target = Some(value),
+            Possible::None => *target = None,
+            Possible::Void => {}
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn check(&self, _target: &Option<T>) -> Result<(), PatchError> {
+        Ok(())
+    }
+}
+
+impl<T> Patch<T> for Possible<T> {
+    #[inline]
+    fn apply(self, target: &mut T) -> Result<(), PatchError> {
+        match self {
+            Possible::Some(value) => *target = value,
+            Possible::None => return Err(PatchError::NotNullable),
+            Possible::Void => {}
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn check(&self, _target: &T) -> Result<(), PatchError> {
+        match self {
+            Possible::None => Err(PatchError::NotNullable),
+            Possible::Some(_) | Possible::Void => Ok(()),
+        }
+    }
+}
+
+/// Associates a type with the patch used to update it recursively.
+///
+/// This is implemented by `#[derive(Patch)]` for the original struct, so that fields marked
+/// `#[patch(nested)]` can be patched with the generated patch struct of their type. Maps
+/// are patched by a map of `Possible` values, where `Possible::None` removes a key.
+///
+/// # Examples
+///
+/// ```
+/// use possible::{Patch, Patchable, Possible};
+/// use std::collections::BTreeMap;
+///
+/// let mut tags = BTreeMap::new();
+/// tags.insert("color", "orange");
+/// tags.insert("size", "small");
+///
+/// let mut patch: <BTreeMap<&str, &str> as Patchable>::Patch = BTreeMap::new();
+/// patch.insert("color", Possible::Some("red"));
+/// patch.insert("size", Possible::None);
+///
+/// patch.apply(&mut tags).unwrap();
+/// assert_eq!(tags.get("color"), Some(&"red"));
+/// assert_eq!(tags.get("size"), None);
+/// ```
+pub trait Patchable {
+    /// The patch type that recursively updates `Self`.
+    type Patch: Patch<Self>;
+
+    /// Returns a patch that overwrites a value with `self`.
+    ///
+    /// This is used to merge a patch that clears an optional value with a newer patch
+    /// that sets it again, which applies the newer patch to the default value. A map patch
+    /// cannot name the keys it does not know, so it only overwrites the keys of `self`,
+    /// and fields marked `#[patch(skip)]` are left out of the patch of a struct.
+    fn into_patch(self) -> Self::Patch;
+}
+
+/// Combines two patches of the same target.
+///
+/// Applying `older.merge(newer)` has the same effect as applying `older` followed by `newer`.
+/// For a single [`Possible`], this holds when the target is an `Option`, since a
+/// [`Possible::None`] followed by a [`Possible::Some`] sets a value that cannot be null
+/// rather than failing; patches of such a value are merged with
+/// [`Possible::merge_non_nullable`], as `#[derive(Patch)]` does for fields that are not an
+/// `Option`.
+pub trait Merge {
+    /// Combines `self` with a `newer` patch that takes precedence.
+    fn merge(self, newer: Self) -> Self;
+}
+
+impl<T> Merge for Possible<T> {
+    #[inline]
+    fn merge(self, newer: Self) -> Self {
+        Possible::merge(self, newer)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> Patch<HashMap<K, V, S>> for HashMap<K, Possible<V>, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn apply(self, target: &mut HashMap<K, V, S>) -> Result<(), PatchError> {
+        for (key, value) in self {
+            match value {
+                Possible::Some(value) => {
+                    target.insert(key, value);
+                }
+                Possible::None => {
+                    target.remove(&key);
+                }
+                Possible::Void => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check(&self, _target: &HashMap<K, V, S>) -> Result<(), PatchError> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> Patchable for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type Patch = HashMap<K, Possible<V>, S>;
+
+    fn into_patch(self) -> Self::Patch {
+        self.into_iter()
+            .map(|(key, value)| (key, Possible::Some(value)))
+            .collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> Merge for HashMap<K, Possible<V>, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn merge(mut self, newer: Self) -> Self {
+        for (key, value) in newer {
+            let merged = match self.remove(&key) {
+                Some(older) => older.merge(value),
+                None => value,
+            };
+            self.insert(key, merged);
+        }
+
+        self
+    }
+}
+
+impl<K: Ord, V> Patch<BTreeMap<K, V>> for BTreeMap<K, Possible<V>> {
+    fn apply(self, target: &mut BTreeMap<K, V>) -> Result<(), PatchError> {
+        for (key, value) in self {
+            match value {
+                Possible::Some(value) => {
+                    target.insert(key, value);
+                }
+                Possible::None => {
+                    target.remove(&key);
+                }
+                Possible::Void => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check(&self, _target: &BTreeMap<K, V>) -> Result<(), PatchError> {
+        Ok(())
+    }
+}
+
+impl<K: Ord, V> Patchable for BTreeMap<K, V> {
+    type Patch = BTreeMap<K, Possible<V>>;
+
+    fn into_patch(self) -> Self::Patch {
+        self.into_iter()
+            .map(|(key, value)| (key, Possible::Some(value)))
+            .collect()
+    }
+}
+
+impl<K: Ord, V> Merge for BTreeMap<K, Possible<V>> {
+    fn merge(mut self, newer: Self) -> Self {
+        for (key, value) in newer {
+            let merged = match self.remove(&key) {
+                Some(older) => older.merge(value),
+                None => value,
+            };
+            self.insert(key, merged);
+        }
+
+        self
+    }
+}
+
+impl<T> Possible<T> {
+    /// Combines two patches of the same value, where `newer` takes precedence
+    /// unless it is [`Possible::Void`].
+    ///
+    /// Applying the merged patch to an `Option` has the same effect as applying `self`
+    /// followed by `newer`. A value that cannot be null fails to apply a [`Possible::None`],
+    /// so patches of such a value are merged with [`merge_non_nullable`] instead.
+    ///
+    /// [`merge_non_nullable`]: Possible::merge_non_nullable
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::Possible;
+    ///
+    /// assert_eq!(Possible::Some(1).merge(Possible::Some(2)), Possible::Some(2));
+    /// assert_eq!(Possible::Some(1).merge(Possible::None), Possible::None);
+    /// assert_eq!(Possible::Some(1).merge(Possible::Void), Possible::Some(1));
+    /// assert_eq!(Possible::None.merge(Possible::Void), Possible::<u32>::None);
+    /// assert_eq!(Possible::Void.merge(Possible::Some(2)), Possible::Some(2));
+    /// ```
+    #[inline]
+    pub fn merge(self, newer: Possible<T>) -> Possible<T> {
+        match newer {
+            Possible::Void => self,
+            Possible::Some(_) | Possible::None => newer,
+        }
+    }
+
+    /// Combines two patches of a value that cannot be null, where `newer` takes precedence
+    /// unless it is [`Possible::Void`] or `self` is [`Possible::None`].
+    ///
+    /// A [`Possible::None`] fails to apply to such a value, so it is kept over a newer
+    /// [`Possible::Some`], and applying the merged patch has the same effect as applying
+    /// `self` followed by `newer`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::Possible;
+    ///
+    /// assert_eq!(Possible::Some(1).merge_non_nullable(Possible::Some(2)), Possible::Some(2));
+    /// assert_eq!(Possible::Some(1).merge_non_nullable(Possible::None), Possible::None);
+    /// assert_eq!(Possible::None.merge_non_nullable(Possible::Some(2)), Possible::None);
+    /// assert_eq!(Possible::Void.merge_non_nullable(Possible::Some(2)), Possible::Some(2));
+    /// ```
+    #[inline]
+    pub fn merge_non_nullable(self, newer: Possible<T>) -> Possible<T> {
+        match (self, newer) {
+            (Possible::None, Possible::Some(_)) => Possible::None,
+            (older, newer) => older.merge(newer),
+        }
+    }
+
+    /// Combines two nested patches of a value that cannot be null, merging them recursively
+    /// when both are [`Possible::Some`].
+    ///
+    /// A [`Possible::None`] fails to apply to such a value, so it is kept over a newer
+    /// [`Possible::Some`]. Otherwise `newer` takes precedence unless it is
+    /// [`Possible::Void`], as with [`merge`].
+    ///
+    /// [`merge`]: Possible::merge
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::Possible;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let older = Possible::Some(BTreeMap::from([("a", Possible::Some(1))]));
+    /// let newer = Possible::Some(BTreeMap::from([("b", Possible::None)]));
+    ///
+    /// assert_eq!(
+    ///     older.merge_nested(newer),
+    ///     Possible::Some(BTreeMap::from([("a", Possible::Some(1)), ("b", Possible::None)])),
+    /// );
+    ///
+    /// let older: Possible<BTreeMap<&str, Possible<u32>>> = Possible::None;
+    /// let newer = Possible::Some(BTreeMap::from([("b", Possible::Some(2))]));
+    /// assert_eq!(older.merge_nested(newer), Possible::None);
+    /// ```
+    #[inline]
+    pub fn merge_nested(self, newer: Possible<T>) -> Possible<T>
+    where
+        T: Merge,
+    {
+        match (self, newer) {
+            (Possible::Some(older), Possible::Some(newer)) => Possible::Some(older.merge(newer)),
+            (older, newer) => older.merge_non_nullable(newer),
+        }
+    }
+
+    /// Combines two nested patches of an optional value `U`, merging them recursively when
+    /// both are [`Possible::Some`].
+    ///
+    /// A newer [`Possible::Some`] following a [`Possible::None`] applies to the default
+    /// value of `U` rather than to the current value, so it is merged with a patch that
+    /// overwrites the current value with the default one, from [`Patchable::into_patch`].
+    /// Otherwise `newer` takes precedence unless it is [`Possible::Void`], as with
+    /// [`merge`].
+    ///
+    /// [`merge`]: Possible::merge
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::{Patch, Possible};
+    /// use std::collections::BTreeMap;
+    ///
+    /// type Scores = BTreeMap<&'static str, u32>;
+    ///
+    /// let older: Possible<BTreeMap<&str, Possible<u32>>> = Possible::None;
+    /// let newer = Possible::Some(BTreeMap::from([("ferris", Possible::Some(7))]));
+    /// let merged = older.merge_nested_option::<Scores>(newer);
+    ///
+    /// let mut scores = None;
+    /// merged.apply_nested_option(&mut scores).unwrap();
+    /// assert_eq!(scores, Some(BTreeMap::from([("ferris", 7)])));
+    /// ```
+    #[inline]
+    pub fn merge_nested_option<U>(self, newer: Possible<T>) -> Possible<T>
+    where
+        T: Merge,
+        U: Patchable<Patch = T> + Default,
+    {
+        match (self, newer) {
+            (Possible::Some(older), Possible::Some(newer)) => Possible::Some(older.merge(newer)),
+            (Possible::None, Possible::Some(newer)) => {
+                Possible::Some(U::default().into_patch().merge(newer))
+            }
+            (older, newer) => older.merge(newer),
+        }
+    }
+
+    /// Applies a nested patch recursively to `target`.
+    ///
+    /// [`Possible::Some`] applies the contained patch to `target`, [`Possible::Void`] leaves
+    /// `target` unchanged, and [`Possible::None`] is an error since `target` cannot be null.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::{PatchError, Possible};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut scores = BTreeMap::from([("ferris", 7), ("corro", 3)]);
+    ///
+    /// let patch = Possible::Some(BTreeMap::from([("corro", Possible::None)]));
+    /// patch.apply_nested(&mut scores).unwrap();
+    /// assert_eq!(scores, BTreeMap::from([("ferris", 7)]));
+    ///
+    /// let patch: Possible<BTreeMap<&str, Possible<u32>>> = Possible::None;
+    /// assert_eq!(patch.apply_nested(&mut scores), Err(PatchError::NotNullable));
+    /// ```
+    #[inline]
+    pub fn apply_nested<U>(self, target: &mut U) -> Result<(), PatchError>
+    where
+        T: Patch<U>,
+    {
+        match self {
+            Possible::Some(patch) => patch.apply(target),
+            Possible::None => Err(PatchError::NotNullable),
+            Possible::Void => Ok(()),
+        }
+    }
+
+    /// Checks that a nested patch can be applied to `target` with
+    /// [`apply_nested`](Possible::apply_nested), without changing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::{PatchError, Possible};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let scores = BTreeMap::from([("ferris", 7)]);
+    ///
+    /// let patch: Possible<BTreeMap<&str, Possible<u32>>> = Possible::None;
+    /// assert_eq!(patch.check_nested(&scores), Err(PatchError::NotNullable));
+    /// ```
+    #[inline]
+    pub fn check_nested<U>(&self, target: &U) -> Result<(), PatchError>
+    where
+        T: Patch<U>,
+    {
+        match self {
+            Possible::Some(patch) => patch.check(target),
+            Possible::None => Err(PatchError::NotNullable),
+            Possible::Void => Ok(()),
+        }
+    }
+
+    /// Applies a nested patch recursively to an optional `target`.
+    ///
+    /// [`Possible::Some`] applies the contained patch to the value in `target`, starting
+    /// from the default value when `target` is `None`. [`Possible::None`] clears `target`
+    /// and [`Possible::Void`] leaves it unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::Possible;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut scores: Option<BTreeMap<&str, u32>> = None;
+    ///
+    /// let patch = Possible::Some(BTreeMap::from([("ferris", Possible::Some(7))]));
+    /// patch.apply_nested_option(&mut scores).unwrap();
+    /// assert_eq!(scores, Some(BTreeMap::from([("ferris", 7)])));
+    ///
+    /// let patch: Possible<BTreeMap<&str, Possible<u32>>> = Possible::None;
+    /// patch.apply_nested_option(&mut scores).unwrap();
+    /// assert_eq!(scores, None);
+    /// ```
+    #[inline]
+    pub fn apply_nested_option<U>(self, target: &mut Option<U>) -> Result<(), PatchError>
+    where
+        T: Patch<U>,
+        U: Default,
+    {
+        match self {
+            Possible::Some(patch) => patch.apply(target.get_or_insert_with(U::default)),
+            Possible::None => {
+                *target = None;
+                Ok(())
+            }
+            Possible::Void => Ok(()),
+        }
+    }
+
+    /// Checks that a nested patch can be applied to an optional `target` with
+    /// [`apply_nested_option`](Possible::apply_nested_option), without changing it.
+    ///
+    /// A patch for a `target` that is `None` is checked against the default value.
+    #[inline]
+    pub fn check_nested_option<U>(&self, target: &Option<U>) -> Result<(), PatchError>
+    where
+        T: Patch<U>,
+        U: Default,
+    {
+        match (self, target) {
+            (Possible::Some(patch), Some(target)) => patch.check(target),
+            (Possible::Some(patch), None) => patch.check(&U::default()),
+            (Possible::None, _) | (Possible::Void, _) => Ok(()),
+        }
+    }
+}
+
+/// Error returned when a [`Patch`] cannot be applied to its target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatchError {
+    /// A [`Possible::None`] was applied to a target that cannot be null.
+    NotNullable,
+    /// Applying the patch of the named field failed.
+    Field(&'static str, Box<PatchError>),
+}
+
+impl PatchError {
+    /// Wraps `source` as the error of applying the patch of the field called `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::PatchError;
+    ///
+    /// let error = PatchError::field("name", PatchError::NotNullable);
+    /// assert_eq!(error.to_string(), "field `name`: value cannot be null");
+    /// ```
+    pub fn field(name: &'static str, source: PatchError) -> PatchError {
+        PatchError::Field(name, Box::new(source))
+    }
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PatchError::NotNullable => formatter.write_str("value cannot be null"),
+            PatchError::Field(name, source) => write!(formatter, "field `{}`: {}", name, source),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for PatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PatchError::NotNullable => None,
+            PatchError::Field(_, source) => Some(source.as_ref()),
+        }
+    }
+}
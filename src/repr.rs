@@ -0,0 +1,141 @@
+//! Serialization support for `Possible`.
+//!
+//! By default a `Possible` is written as its contained value for `Possible::Some`, a null
+//! value for `Possible::None`, and a unit value for `Possible::Void`, which most formats
+//! cannot tell apart from null. The `as_*` modules in here provide alternative
+//! representations for use with `#[serde(with = "...")]`, so that each format can
+//! round-trip every state of a `Possible` without losing information.
+//!
+//! | Module            | `Some(value)` | `None`          | `Void`               |
+//! |-------------------|---------------|-----------------|----------------------|
+//! | [`as_tagged`]     | tagged value  | tagged `none`   | tagged `void`        |
+//! | [`as_sentinel`]   | value         | null            | `"__undefined__"`    |
+//! | [`as_null_void`]  | value         | error           | null                 |
+//! | [`as_empty_none`] | value         | `""`            | null or missing      |
+//! | [`as_query`]      | value as text | `""`            | missing              |
+//! | [`as_deny_void`]  | value         | null            | error                |
+//!
+//! The [`as_tagged`] and [`as_query`] modules require the `alloc` feature.
+//!
+//! With the `serde_urlencoded` feature enabled, the `urlencoded` module reads and writes
+//! query strings with sequences as repeated keys, which `serde_urlencoded` cannot represent.
+//!
+//! TOML has no null value at all, so the `toml` module, behind the `toml` feature,
+//! provides strategies that represent `Possible::None` in other ways.
+//!
+//! For error reporting, [`Possible::deserialize_field`] starts the error of a field that
+//! failed to deserialize with its [`FieldState`], and [`Possible::require_field`] returns a
+//! [`FieldError`] with the state of a field that was null or missing.
+//!
+//! With the `serde_with` feature enabled, `PossibleAs` applies a `serde_with` adapter to
+//! the value inside a `Possible` while keeping this representation of the other states.
+
+pub mod as_deny_void;
+pub mod as_empty_none;
+pub mod as_null_void;
+#[cfg(feature = "alloc")]
+pub mod as_query;
+pub mod as_sentinel;
+#[cfg(feature = "alloc")]
+pub mod as_tagged;
+mod field;
+mod forward;
+#[cfg(feature = "serde_with")]
+mod possible_as;
+#[cfg(feature = "alloc")]
+mod text;
+#[cfg(feature = "toml")]
+pub mod toml;
+#[cfg(feature = "serde_urlencoded")]
+pub mod urlencoded;
+
+pub use field::{FieldError, FieldState};
+#[cfg(feature = "serde_with")]
+pub use possible_as::PossibleAs;
+
+use super::Possible;
+use core::{any::type_name, fmt, marker::PhantomData};
+use serde::{
+    de::{Error, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Visitor that reads a null value as `Possible::None`, a unit value as `Possible::Void`,
+/// and the value of an option as `Possible::Some`.
+struct PossibleVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for PossibleVisitor<T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = Possible<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        expecting::<T>(formatter)
+    }
+
+    #[inline]
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(Possible::Void)
+    }
+
+    #[inline]
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(Possible::None)
+    }
+
+    #[inline]
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Possible::Some)
+    }
+}
+
+fn possible_visitor<T>() -> PossibleVisitor<T> {
+    PossibleVisitor(PhantomData)
+}
+
+/// Writes what a visitor of `Possible<T>` expects, naming the type `T`.
+fn expecting<T>(formatter: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+        formatter,
+        "a value of type `{}`, null, or unit",
+        type_name::<T>()
+    )
+}
+
+impl<'de, T> Deserialize<'de> for Possible<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(possible_visitor())
+    }
+}
+
+impl<T> Serialize for Possible<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Possible::Some(ref value) => serializer.serialize_some(value),
+            Possible::None => serializer.serialize_none(),
+            Possible::Void => serializer.serialize_unit(),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! Rejects `Possible::Void`, requiring every field to be either a value or null.
+//!
+//! Serializing `Possible::Void` is an error, and so is deserializing a missing field or a
+//! unit value. This suits formats and APIs that have no way to express an absent value.
+//!
+//! # Examples
+//!
+//! ```
+//! use possible::Possible;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize, PartialEq)]
+//! struct ParseTest {
+//!     #[serde(with = "possible::repr::as_deny_void")]
+//!     test: Possible<i64>,
+//! }
+//!
+//! let parsed: ParseTest = serde_json::from_str(r#"{"test":null}"#).unwrap();
+//! assert_eq!(parsed, ParseTest { test: Possible::None });
+//!
+//! assert!(serde_json::from_str::<ParseTest>(r#"{}"#).is_err());
+//!
+//! let data = ParseTest { test: Possible::Void };
+//! assert!(serde_json::to_string(&data).is_err());
+//! ```
+
+use super::{super::Possible, possible_visitor};
+use serde::{
+    de::{self, Unexpected},
+    ser, Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Serializes a `Possible`.
+///
+/// # Errors
+///
+/// Fails if the value is `Possible::Void`.
+pub fn serialize<T, S>(value: &Possible<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    match value {
+        Possible::Some(value) => serializer.serialize_some(value),
+        Possible::None => serializer.serialize_none(),
+        Possible::Void => Err(<S::Error as ser::Error>::custom(
+            "`Possible::Void` cannot be serialized",
+        )),
+    }
+}
+
+/// Deserializes a `Possible`.
+///
+/// # Errors
+///
+/// Fails if the value is a unit value, which would otherwise be read as `Possible::Void`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Possible<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    match deserializer.deserialize_option(possible_visitor())? {
+        Possible::Void => Err(<D::Error as de::Error>::invalid_type(
+            Unexpected::Unit,
+            &"a value or null",
+        )),
+        value => Ok(value),
+    }
+}
@@ -0,0 +1,65 @@
+//! Represents `Possible::None` as an empty string.
+//!
+//! This suits formats such as URL query strings and HTML forms, where a key without a value
+//! is the closest thing to an explicit null. `Possible::Void` is written as a missing value,
+//! and a null or unit value is read as `Possible::Void`. Deserializing requires a
+//! self-describing format, and `Possible::Some` of an empty string cannot be represented.
+//!
+//! # Examples
+//!
+//! ```
+//! use possible::Possible;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize, PartialEq)]
+//! struct ParseTest {
+//!     #[serde(default, with = "possible::repr::as_empty_none")]
+//!     #[serde(skip_serializing_if = "Possible::is_void")]
+//!     test: Possible<String>,
+//! }
+//!
+//! let data = ParseTest { test: Possible::None };
+//! let serialized = serde_qs::to_string(&data).unwrap();
+//! assert_eq!(serialized, "test=");
+//!
+//! let parsed: ParseTest = serde_qs::from_str(&serialized).unwrap();
+//! assert_eq!(parsed, data);
+//! ```
+
+use super::{super::Possible, forward::Forward};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes a `Possible`, writing `Possible::None` as an empty string.
+pub fn serialize<T, S>(value: &Possible<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    match value {
+        Possible::Some(value) => serializer.serialize_some(value),
+        Possible::None => serializer.serialize_str(""),
+        Possible::Void => serializer.serialize_none(),
+    }
+}
+
+/// Deserializes a `Possible`, reading an empty string as `Possible::None`.
+///
+/// # Errors
+///
+/// Fails if the format is not self-describing.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Possible<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(Forward::new(
+        || Possible::Void,
+        |value| {
+            if value.is_empty() {
+                Some(Possible::None)
+            } else {
+                None
+            }
+        },
+    ))
+}
@@ -0,0 +1,60 @@
+//! Represents `Possible::Void` as a null value.
+//!
+//! This suits formats and APIs where a null value means "not provided". `Possible::None`
+//! cannot be represented, so serializing it is an error rather than silently turning it
+//! into `Possible::Void`.
+//!
+//! # Examples
+//!
+//! ```
+//! use possible::Possible;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize, PartialEq)]
+//! struct ParseTest {
+//!     #[serde(default, with = "possible::repr::as_null_void")]
+//!     test: Possible<i64>,
+//! }
+//!
+//! let parsed: ParseTest = serde_json::from_str(r#"{"test":null}"#).unwrap();
+//! assert_eq!(parsed, ParseTest { test: Possible::Void });
+//!
+//! let data = ParseTest { test: Possible::None };
+//! assert!(serde_json::to_string(&data).is_err());
+//! ```
+
+use super::{super::Possible, possible_visitor};
+use serde::{ser::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes a `Possible`, writing `Possible::Void` as a null value.
+///
+/// # Errors
+///
+/// Fails if the value is `Possible::None`.
+pub fn serialize<T, S>(value: &Possible<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    match value {
+        Possible::Some(value) => serializer.serialize_some(value),
+        Possible::None => Err(S::Error::custom(
+            "`Possible::None` cannot be represented when null means `Possible::Void`",
+        )),
+        Possible::Void => serializer.serialize_none(),
+    }
+}
+
+/// Deserializes a `Possible`, reading a null value as `Possible::Void`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Possible<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let value = deserializer.deserialize_option(possible_visitor())?;
+
+    Ok(match value {
+        Possible::Some(value) => Possible::Some(value),
+        Possible::None | Possible::Void => Possible::Void,
+    })
+}
@@ -0,0 +1,253 @@
+//! Represents every state of a `Possible` in URL query strings and HTML forms.
+//!
+//! A key without a value, such as `test=`, is read as `Possible::None`, and a missing key
+//! is read as `Possible::Void`, which requires
+//! `#[serde(default, skip_serializing_if = "Possible::is_void")]` as with any other format.
+//! Values are parsed from their text, so this works with both `serde_qs` and
+//! `serde_urlencoded`. Serializing writes `Possible::None` as an empty value, and rejects
+//! a `Possible::Some` whose value would be read back as `Possible::None`.
+//!
+//! The [`seq`] module reads `Possible<Vec<T>>` fields from the bracketed keys of
+//! `serde_qs`, such as `tags[]=a&tags[]=b`, while a single value without brackets is read
+//! as a sequence of one element. With the `serde_urlencoded` feature enabled,
+//! [`urlencoded`](super::urlencoded) also reads and writes sequences as repeated keys.
+//!
+//! Another convention for `Possible::None` can be used by implementing [`Convention`] and
+//! passing it to the `*_with` functions, such as [`NullLiteral`] which also reads the
+//! literal `null` as `Possible::None`.
+//!
+//! # Examples
+//!
+//! ```
+//! use possible::Possible;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize, PartialEq)]
+//! struct Query {
+//!     #[serde(default, skip_serializing_if = "Possible::is_void")]
+//!     #[serde(with = "possible::repr::as_query")]
+//!     page: Possible<u32>,
+//!     #[serde(default, skip_serializing_if = "Possible::is_void")]
+//!     #[serde(with = "possible::repr::as_query")]
+//!     search: Possible<String>,
+//!     #[serde(default, skip_serializing_if = "Possible::is_void")]
+//!     #[serde(with = "possible::repr::as_query::seq")]
+//!     tags: Possible<Vec<String>>,
+//! }
+//!
+//! let query = Query {
+//!     page: Possible::Some(2),
+//!     search: Possible::None,
+//!     tags: Possible::Void,
+//! };
+//!
+//! let serialized = serde_qs::to_string(&query).unwrap();
+//! assert_eq!(serialized, "page=2&search=");
+//! assert_eq!(serde_qs::from_str::<Query>(&serialized).unwrap(), query);
+//!
+//! let parsed: Query = serde_qs::from_str("tags[]=a&tags[]=b").unwrap();
+//! assert_eq!(parsed.tags, Possible::Some(vec![String::from("a"), String::from("b")]));
+//! ```
+
+use super::{super::Possible, as_empty_none, forward::Forward, text::to_text};
+use alloc::{format, string::String};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The values of a query string that represent `Possible::None`.
+pub trait Convention {
+    /// A literal value that is read as `Possible::None` besides an empty value, if any.
+    const NULL: Option<&'static str>;
+}
+
+/// The default convention, where only an empty value is `Possible::None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Empty;
+
+impl Convention for Empty {
+    const NULL: Option<&'static str> = None;
+}
+
+/// A convention where both an empty value and the literal `null` are `Possible::None`.
+///
+/// # Examples
+///
+/// ```
+/// use possible::{repr::as_query::NullLiteral, Possible};
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, Deserialize, PartialEq)]
+/// struct Query {
+///     #[serde(default)]
+///     #[serde(deserialize_with = "possible::repr::as_query::deserialize_with::<NullLiteral, _, _>")]
+///     page: Possible<u32>,
+/// }
+///
+/// let parsed: Query = serde_urlencoded::from_str("page=null").unwrap();
+/// assert_eq!(parsed, Query { page: Possible::None });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NullLiteral;
+
+impl Convention for NullLiteral {
+    const NULL: Option<&'static str> = Some("null");
+}
+
+fn is_null<C: Convention>(text: &str) -> bool {
+    text.is_empty() || C::NULL == Some(text)
+}
+
+/// Fails if `value` would be read back as `Possible::None`.
+fn check<C, T>(value: &T) -> Result<(), String>
+where
+    C: Convention,
+    T: Serialize + ?Sized,
+{
+    match to_text(value) {
+        Some(text) if is_null::<C>(&text) => Err(format!(
+            "`Possible::Some` value {:?} is ambiguous with `Possible::None`",
+            text
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Serializes a `Possible`, writing `Possible::None` as an empty value.
+///
+/// # Errors
+///
+/// Fails for a `Possible::Some` that is written as an empty value.
+pub fn serialize<T, S>(value: &Possible<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    serialize_with::<Empty, T, S>(value, serializer)
+}
+
+/// Deserializes a `Possible`, reading an empty value as `Possible::None`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Possible<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserialize_with::<Empty, T, D>(deserializer)
+}
+
+/// Serializes a `Possible`, writing `Possible::None` as an empty value.
+///
+/// # Errors
+///
+/// Fails for a `Possible::Some` that is written as a value of `Possible::None` under the
+/// convention `C`.
+pub fn serialize_with<C, T, S>(value: &Possible<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    C: Convention,
+    T: Serialize,
+    S: Serializer,
+{
+    use serde::ser::Error;
+
+    if let Possible::Some(value) = value {
+        check::<C, T>(value).map_err(S::Error::custom)?;
+    }
+
+    as_empty_none::serialize(value, serializer)
+}
+
+/// Deserializes a `Possible`, reading the values of `Possible::None` under the convention
+/// `C` as `Possible::None`.
+pub fn deserialize_with<'de, C, T, D>(deserializer: D) -> Result<Possible<T>, D::Error>
+where
+    C: Convention,
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(
+        Forward::new(
+            || Possible::Void,
+            |text| {
+                if is_null::<C>(text) {
+                    Some(Possible::None)
+                } else {
+                    None
+                }
+            },
+        )
+        .text(),
+    )
+}
+
+/// Represents a `Possible<Vec<T>>` like [`as_query`](super), with the elements as
+/// bracketed or repeated keys.
+///
+/// An empty `Possible::Some` sequence has no keys to write, so serializing it is an error.
+pub mod seq {
+    use super::{super::super::Possible, super::as_empty_none, check, Convention, Empty};
+    use alloc::vec::Vec;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes a `Possible` sequence, writing `Possible::None` as an empty value.
+    ///
+    /// # Errors
+    ///
+    /// Fails for an empty `Possible::Some` sequence, and for elements that are written as
+    /// an empty value.
+    pub fn serialize<T, S>(values: &Possible<Vec<T>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        serialize_with::<Empty, T, S>(values, serializer)
+    }
+
+    /// Deserializes a `Possible` sequence, reading an empty value as `Possible::None`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Possible<Vec<T>>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserialize_with::<Empty, T, D>(deserializer)
+    }
+
+    /// Serializes a `Possible` sequence, writing `Possible::None` as an empty value.
+    ///
+    /// # Errors
+    ///
+    /// Fails for an empty `Possible::Some` sequence, and for elements that are written as
+    /// a value of `Possible::None` under the convention `C`.
+    pub fn serialize_with<C, T, S>(
+        values: &Possible<Vec<T>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        C: Convention,
+        T: Serialize,
+        S: Serializer,
+    {
+        use serde::ser::Error;
+
+        if let Possible::Some(values) = values {
+            if values.is_empty() {
+                return Err(S::Error::custom(
+                    "an empty `Possible::Some` sequence is ambiguous with `Possible::Void`",
+                ));
+            }
+            for value in values {
+                check::<C, T>(value).map_err(S::Error::custom)?;
+            }
+        }
+
+        as_empty_none::serialize(values, serializer)
+    }
+
+    /// Deserializes a `Possible` sequence, reading the values of `Possible::None` under the
+    /// convention `C` as `Possible::None`.
+    pub fn deserialize_with<'de, C, T, D>(deserializer: D) -> Result<Possible<Vec<T>>, D::Error>
+    where
+        C: Convention,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        super::deserialize_with::<C, Vec<T>, D>(deserializer)
+    }
+}
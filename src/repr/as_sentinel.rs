@@ -0,0 +1,241 @@
+//! Represents `Possible::Void` as the sentinel string [`SENTINEL`].
+//!
+//! `Possible::None` is written as a null value and `Possible::Some` as its contained
+//! value, so that a `Possible::Void` field survives formats that would otherwise drop or
+//! collapse it. Deserializing requires a self-describing format.
+//!
+//! A `Possible::Some` holding a value that serializes as [`SENTINEL`] itself would read
+//! back as `Possible::Void`, so serializing it fails instead.
+//!
+//! # Examples
+//!
+//! ```
+//! use possible::Possible;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize, PartialEq)]
+//! struct ParseTest {
+//!     #[serde(with = "possible::repr::as_sentinel")]
+//!     test: Possible<String>,
+//! }
+//!
+//! let data = ParseTest { test: Possible::Void };
+//! let serialized = serde_json::to_string(&data).unwrap();
+//! assert_eq!(serialized, r#"{"test":"__undefined__"}"#);
+//!
+//! let parsed: ParseTest = serde_json::from_str(&serialized).unwrap();
+//! assert_eq!(parsed, data);
+//!
+//! let data = ParseTest { test: Possible::Some(String::from("__undefined__")) };
+//! assert!(serde_json::to_string(&data).is_err());
+//! ```
+
+use super::{super::Possible, forward::Forward};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    ser::{Error, Impossible},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// The string that represents `Possible::Void`.
+pub const SENTINEL: &str = "__undefined__";
+
+/// Serializes a `Possible`, writing `Possible::Void` as [`SENTINEL`].
+///
+/// # Errors
+///
+/// Fails for a `Possible::Some` that serializes as the sentinel.
+pub fn serialize<T, S>(value: &Possible<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    match value {
+        Possible::Some(value) => {
+            if let Ok(true) = value.serialize(IsSentinel(PhantomData::<S::Error>)) {
+                return Err(S::Error::custom(
+                    "value is reserved to represent `Possible::Void`",
+                ));
+            }
+
+            serializer.serialize_some(value)
+        }
+        Possible::None => serializer.serialize_none(),
+        Possible::Void => serializer.serialize_str(SENTINEL),
+    }
+}
+
+/// Deserializes a `Possible`, reading [`SENTINEL`] as `Possible::Void`.
+///
+/// # Errors
+///
+/// Fails if the format is not self-describing.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Possible<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(Forward::new(
+        || Possible::None,
+        |value| {
+            if value == SENTINEL {
+                Some(Possible::Void)
+            } else {
+                None
+            }
+        },
+    ))
+}
+
+// Checks whether a value serializes as the sentinel string, or as a unit variant of the same
+// name which self-describing formats write as a string too. Any compound value fails early
+// with an error, since it cannot be the sentinel.
+struct IsSentinel<E>(PhantomData<E>);
+
+impl<E: Error> IsSentinel<E> {
+    fn compound<T>(self) -> Result<T, E> {
+        Err(E::custom("not a string"))
+    }
+}
+
+macro_rules! not_sentinel {
+    ($($method:ident($($ty:ty),*),)*) => {
+        $(
+            fn $method(self, $(_: $ty),*) -> Result<bool, E> {
+                Ok(false)
+            }
+        )*
+    };
+}
+
+impl<E: Error> Serializer for IsSentinel<E> {
+    type Ok = bool;
+    type Error = E;
+    type SerializeSeq = Impossible<bool, E>;
+    type SerializeTuple = Impossible<bool, E>;
+    type SerializeTupleStruct = Impossible<bool, E>;
+    type SerializeTupleVariant = Impossible<bool, E>;
+    type SerializeMap = Impossible<bool, E>;
+    type SerializeStruct = Impossible<bool, E>;
+    type SerializeStructVariant = Impossible<bool, E>;
+
+    not_sentinel! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_bytes(&[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(&'static str),
+    }
+
+    fn serialize_str(self, value: &str) -> Result<bool, E> {
+        Ok(value == SENTINEL)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<bool, E> {
+        Ok(variant == SENTINEL)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<bool, E> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<bool, E> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<bool, E> {
+        Ok(false)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, E> {
+        self.compound()
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, E> {
+        self.compound()
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, E> {
+        self.compound()
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, E> {
+        self.compound()
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, E> {
+        self.compound()
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeStruct, E> {
+        self.compound()
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, E> {
+        self.compound()
+    }
+
+    fn collect_str<T: ?Sized + fmt::Display>(self, value: &T) -> Result<bool, E> {
+        // compares the displayed value piece by piece, without allocating
+        struct Compare<'a>(&'a str);
+
+        impl fmt::Write for Compare<'_> {
+            fn write_str(&mut self, piece: &str) -> fmt::Result {
+                match self.0.strip_prefix(piece) {
+                    Some(rest) => {
+                        self.0 = rest;
+                        Ok(())
+                    }
+                    None => Err(fmt::Error),
+                }
+            }
+        }
+
+        let mut compare = Compare(SENTINEL);
+        Ok(fmt::write(&mut compare, format_args!("{}", value)).is_ok() && compare.0.is_empty())
+    }
+}
@@ -0,0 +1,71 @@
+//! Represents every state of a `Possible` explicitly as a tagged value.
+//!
+//! `Possible::Some(value)` is written as `{"state":"some","value":value}`, `Possible::None`
+//! as `{"state":"none"}`, and `Possible::Void` as `{"state":"void"}`. This round-trips
+//! through any format that supports maps, including formats without a null value.
+//!
+//! # Examples
+//!
+//! ```
+//! use possible::Possible;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize, PartialEq)]
+//! struct ParseTest {
+//!     #[serde(with = "possible::repr::as_tagged")]
+//!     test: Possible<i64>,
+//! }
+//!
+//! let data = ParseTest { test: Possible::Void };
+//! let serialized = serde_json::to_string(&data).unwrap();
+//! assert_eq!(serialized, r#"{"test":{"state":"void"}}"#);
+//!
+//! let parsed: ParseTest = serde_json::from_str(r#"{"test":{"state":"some","value":123}}"#).unwrap();
+//! assert_eq!(parsed, ParseTest { test: Possible::Some(123) });
+//! ```
+
+use super::super::Possible;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize)]
+#[serde(tag = "state", content = "value", rename_all = "lowercase")]
+enum TaggedRef<'a, T> {
+    Some(&'a T),
+    None,
+    Void,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "state", content = "value", rename_all = "lowercase")]
+enum Tagged<T> {
+    Some(T),
+    None,
+    Void,
+}
+
+/// Serializes a `Possible` as a tagged value.
+pub fn serialize<T, S>(value: &Possible<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    match value {
+        Possible::Some(value) => TaggedRef::Some(value),
+        Possible::None => TaggedRef::None,
+        Possible::Void => TaggedRef::Void,
+    }
+    .serialize(serializer)
+}
+
+/// Deserializes a `Possible` from a tagged value.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Possible<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Ok(match Tagged::deserialize(deserializer)? {
+        Tagged::Some(value) => Possible::Some(value),
+        Tagged::None => Possible::None,
+        Tagged::Void => Possible::Void,
+    })
+}
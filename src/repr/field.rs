@@ -0,0 +1,154 @@
+//! Deserialization errors that record the state of the field that failed.
+
+use super::super::Possible;
+use core::fmt;
+use serde::{de, Deserialize, Deserializer};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Whether a field was present, null, or missing in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldState {
+    /// The field had a value, read as [`Possible::Some`].
+    Present,
+    /// The field was an explicit null, read as [`Possible::None`].
+    Null,
+    /// The field was absent, read as [`Possible::Void`].
+    Missing,
+}
+
+impl fmt::Display for FieldState {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match self {
+            FieldState::Present => "present",
+            FieldState::Null => "null",
+            FieldState::Missing => "missing",
+        })
+    }
+}
+
+/// Error from deserializing a `Possible`, together with the [`FieldState`] of the input.
+///
+/// This is returned by [`Possible::require_field`] for a null or missing field, and is the
+/// message of the errors from [`Possible::deserialize_field`]. It can also be constructed
+/// with [`FieldError::new`] for other errors found while validating an already
+/// deserialized field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError<E> {
+    state: FieldState,
+    error: E,
+}
+
+impl<E> FieldError<E> {
+    /// Creates an error for a field in the given `state`.
+    pub fn new(state: FieldState, error: E) -> FieldError<E> {
+        FieldError { state, error }
+    }
+
+    /// Returns the state of the field in the input.
+    pub fn state(&self) -> FieldState {
+        self.state
+    }
+
+    /// Returns a reference to the underlying error.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Returns the underlying error.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for FieldError<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{} field: {}", self.state, self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> Error for FieldError<E>
+where
+    E: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl<T> Possible<T> {
+    /// Returns the [`FieldState`] that this `Possible` is read from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::{repr::FieldState, Possible};
+    ///
+    /// assert_eq!(Possible::Some(2).state(), FieldState::Present);
+    /// assert_eq!(Possible::<u32>::None.state(), FieldState::Null);
+    /// assert_eq!(Possible::<u32>::Void.state(), FieldState::Missing);
+    /// ```
+    #[inline]
+    pub fn state(&self) -> FieldState {
+        match self {
+            Possible::Some(_) => FieldState::Present,
+            Possible::None => FieldState::Null,
+            Possible::Void => FieldState::Missing,
+        }
+    }
+
+    /// Deserializes a `Possible` like its `Deserialize` implementation, with the
+    /// [`FieldState`] of the input at the start of the error message.
+    ///
+    /// Only a present value can fail to deserialize, since a null or missing field is read
+    /// as `Possible::None` or `Possible::Void`, so the state in the message is always
+    /// [`FieldState::Present`]. Use [`Possible::require_field`] to report a null or missing
+    /// field once the input is deserialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::Possible;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Debug, Deserialize)]
+    /// struct ParseTest {
+    ///     #[serde(default, deserialize_with = "Possible::deserialize_field")]
+    ///     test: Possible<u32>,
+    /// }
+    ///
+    /// let error = serde_json::from_str::<ParseTest>(r#"{"test":"seven"}"#).unwrap_err();
+    /// assert!(error.to_string().starts_with("present field: invalid type: string \"seven\""));
+    /// ```
+    pub fn deserialize_field<'de, D>(deserializer: D) -> Result<Possible<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Possible::deserialize(deserializer)
+            .map_err(|error| de::Error::custom(FieldError::new(FieldState::Present, error)))
+    }
+
+    /// Returns the contained value of a field that must be present and not null, or a
+    /// [`FieldError`] with the state of a null or missing field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::{repr::FieldState, Possible};
+    ///
+    /// assert_eq!(Possible::Some(2).require_field(), Ok(2));
+    ///
+    /// let error = Possible::<u32>::Void.require_field().unwrap_err();
+    /// assert_eq!(error.state(), FieldState::Missing);
+    /// assert_eq!(error.to_string(), "missing field: value is required");
+    /// ```
+    pub fn require_field(self) -> Result<T, FieldError<&'static str>> {
+        match self {
+            Possible::Some(value) => Ok(value),
+            Possible::None => Err(FieldError::new(FieldState::Null, "value must not be null")),
+            Possible::Void => Err(FieldError::new(FieldState::Missing, "value is required")),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use super::super::Possible;
+use core::{fmt, marker::PhantomData};
+use serde::de::{
+    value::{
+        BoolDeserializer, BorrowedBytesDeserializer, BorrowedStrDeserializer, BytesDeserializer,
+        CharDeserializer, EnumAccessDeserializer, F64Deserializer, I64Deserializer,
+        MapAccessDeserializer, SeqAccessDeserializer, StrDeserializer, U64Deserializer,
+    },
+    Deserialize, Deserializer, EnumAccess, Error, MapAccess, SeqAccess, Visitor,
+};
+#[cfg(feature = "alloc")]
+use {
+    super::text::TextDeserializer,
+    alloc::{borrow::Cow, string::String},
+    serde::de::value::StringDeserializer,
+};
+
+/// Visitor for `deserialize_any` that deserializes `T` from whichever value a
+/// self-describing format visits, unless the value is a null or a string that
+/// represents another state of `Possible`.
+///
+/// Errors for a value that `T` does not accept come from `T` itself, and so name what
+/// `T` expected, while values that no visitor method accepts name the type `T`.
+pub(super) struct Forward<T> {
+    /// The state of a null or unit value.
+    null: fn() -> Possible<T>,
+    /// The state of a string value, or `None` to deserialize the string as `T`.
+    string: fn(&str) -> Option<Possible<T>>,
+    /// Whether strings are parsed for numbers and other scalar types of `T`.
+    #[cfg(feature = "alloc")]
+    text: bool,
+    marker: PhantomData<T>,
+}
+
+impl<T> Forward<T> {
+    pub(super) fn new(null: fn() -> Possible<T>, string: fn(&str) -> Option<Possible<T>>) -> Self {
+        Forward {
+            null,
+            string,
+            #[cfg(feature = "alloc")]
+            text: false,
+            marker: PhantomData,
+        }
+    }
+
+    /// Parses strings for numbers and other scalar types of `T`, for formats that only have
+    /// strings.
+    #[cfg(feature = "alloc")]
+    pub(super) fn text(self) -> Self {
+        Forward { text: true, ..self }
+    }
+}
+
+impl<'de, T> Visitor<'de> for Forward<T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = Possible<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        super::expecting::<T>(formatter)
+    }
+
+    fn visit_bool<E: Error>(self, v: bool) -> Result<Self::Value, E> {
+        T::deserialize(BoolDeserializer::new(v)).map(Possible::Some)
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        T::deserialize(I64Deserializer::new(v)).map(Possible::Some)
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        T::deserialize(U64Deserializer::new(v)).map(Possible::Some)
+    }
+
+    fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+        T::deserialize(F64Deserializer::new(v)).map(Possible::Some)
+    }
+
+    fn visit_char<E: Error>(self, v: char) -> Result<Self::Value, E> {
+        T::deserialize(CharDeserializer::new(v)).map(Possible::Some)
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        match (self.string)(v) {
+            Some(state) => Ok(state),
+            #[cfg(feature = "alloc")]
+            None if self.text => T::deserialize(TextDeserializer::new(Cow::Owned(String::from(v))))
+                .map(Possible::Some),
+            None => T::deserialize(StrDeserializer::new(v)).map(Possible::Some),
+        }
+    }
+
+    fn visit_borrowed_str<E: Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        match (self.string)(v) {
+            Some(state) => Ok(state),
+            #[cfg(feature = "alloc")]
+            None if self.text => {
+                T::deserialize(TextDeserializer::new(Cow::Borrowed(v))).map(Possible::Some)
+            }
+            None => T::deserialize(BorrowedStrDeserializer::new(v)).map(Possible::Some),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+        match (self.string)(&v) {
+            Some(state) => Ok(state),
+            None if self.text => {
+                T::deserialize(TextDeserializer::new(Cow::Owned(v))).map(Possible::Some)
+            }
+            None => T::deserialize(StringDeserializer::new(v)).map(Possible::Some),
+        }
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        T::deserialize(BytesDeserializer::new(v)).map(Possible::Some)
+    }
+
+    fn visit_borrowed_bytes<E: Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+        T::deserialize(BorrowedBytesDeserializer::new(v)).map(Possible::Some)
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        Ok((self.null)())
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok((self.null)())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Possible::Some)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Possible::Some)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        T::deserialize(SeqAccessDeserializer::new(seq)).map(Possible::Some)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        T::deserialize(MapAccessDeserializer::new(map)).map(Possible::Some)
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        T::deserialize(EnumAccessDeserializer::new(data)).map(Possible::Some)
+    }
+}
@@ -0,0 +1,73 @@
+//! [`serde_with`] adapter that applies a conversion to the value inside a `Possible`.
+
+use super::super::Possible;
+use core::marker::PhantomData;
+use serde::{Deserialize, Deserializer, Serializer};
+use serde_with::{de::DeserializeAsWrap, ser::SerializeAsWrap, DeserializeAs, Same, SerializeAs};
+
+/// Applies the [`serde_with`] adapter `U` to the value inside a `Possible`.
+///
+/// `Possible::None` and `Possible::Void` keep the representation of the `Serialize` and
+/// `Deserialize` implementations of `Possible`, so fields still need
+/// `#[serde(default, skip_serializing_if = "Possible::is_void")]` to omit `Possible::Void`.
+///
+/// # Examples
+///
+/// ```
+/// use possible::{Possible, PossibleAs};
+/// use serde::{Deserialize, Serialize};
+/// use serde_with::{serde_as, DisplayFromStr};
+///
+/// #[serde_as]
+/// #[derive(Debug, Serialize, Deserialize, PartialEq)]
+/// struct ParseTest {
+///     #[serde_as(as = "PossibleAs<DisplayFromStr>")]
+///     #[serde(default, skip_serializing_if = "Possible::is_void")]
+///     id: Possible<u64>,
+///     #[serde_as(as = "PossibleAs<DisplayFromStr>")]
+///     #[serde(default, skip_serializing_if = "Possible::is_void")]
+///     parent: Possible<u64>,
+/// }
+///
+/// let parsed: ParseTest = serde_json::from_str(r#"{ "id": "1324", "parent": null }"#).unwrap();
+/// assert_eq!(parsed, ParseTest {
+///     id: Possible::Some(1324),
+///     parent: Possible::None,
+/// });
+///
+/// let data = ParseTest { id: Possible::Some(7), parent: Possible::Void };
+/// assert_eq!(serde_json::to_string(&data).unwrap(), r#"{"id":"7"}"#);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PossibleAs<U = Same>(PhantomData<U>);
+
+impl<T, U> SerializeAs<Possible<T>> for PossibleAs<U>
+where
+    U: SerializeAs<T>,
+{
+    fn serialize_as<S>(source: &Possible<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match source {
+            Possible::Some(value) => {
+                serializer.serialize_some(&SerializeAsWrap::<T, U>::new(value))
+            }
+            Possible::None => serializer.serialize_none(),
+            Possible::Void => serializer.serialize_unit(),
+        }
+    }
+}
+
+impl<'de, T, U> DeserializeAs<'de, Possible<T>> for PossibleAs<U>
+where
+    U: DeserializeAs<'de, T>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Possible<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Possible::<DeserializeAsWrap<T, U>>::deserialize(deserializer)
+            .map(|value| value.map(DeserializeAsWrap::into_inner))
+    }
+}
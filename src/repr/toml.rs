@@ -0,0 +1,165 @@
+//! Represents every state of a `Possible` in TOML, which has no null value.
+//!
+//! `Possible::Void` is represented by omitting the field, which requires
+//! `#[serde(default, skip_serializing_if = "Possible::is_void")]` as with any other format.
+//! `Possible::None` is represented by one of two strategies:
+//!
+//! - [`sentinel`] writes a reserved string, `"__null__"` unless configured otherwise.
+//! - [`marker`] writes the table `{ null = true }`, which cannot collide with strings.
+//!
+//! Both strategies reject ambiguous values instead of guessing: a `Possible::Some` whose
+//! value reads back as `Possible::None` cannot be serialized, and [`marker`] refuses to
+//! deserialize a table with a `null` key that is not exactly the marker. An array element
+//! cannot be omitted without shifting the elements after it, so the `seq` modules for
+//! `Vec<Possible<T>>` fields reject `Possible::Void` elements.
+//!
+//! Values are converted through [`toml::Value`], so deserializing requires a format that
+//! can produce one, such as TOML itself.
+//!
+//! # Examples
+//!
+//! ```
+//! use possible::Possible;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize, PartialEq)]
+//! struct Config {
+//!     #[serde(default, skip_serializing_if = "Possible::is_void")]
+//!     #[serde(with = "possible::repr::toml::sentinel")]
+//!     proxy: Possible<String>,
+//!     #[serde(default, with = "possible::repr::toml::sentinel::seq")]
+//!     retries: Vec<Possible<u32>>,
+//!     #[serde(default, skip_serializing_if = "Possible::is_void")]
+//!     #[serde(with = "possible::repr::toml::marker")]
+//!     timeout: Possible<u32>,
+//! }
+//!
+//! let config = Config {
+//!     proxy: Possible::Void,
+//!     retries: vec![Possible::Some(1), Possible::None],
+//!     timeout: Possible::None,
+//! };
+//!
+//! let serialized = toml::to_string(&config).unwrap();
+//! assert_eq!(serialized, "retries = [1, \"__null__\"]\n\n[timeout]\nnull = true\n");
+//!
+//! let parsed: Config = toml::from_str(&serialized).unwrap();
+//! assert_eq!(parsed, config);
+//! ```
+
+pub mod marker;
+pub mod sentinel;
+
+use super::super::Possible;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use toml::Value;
+
+/// A representation of `Possible::None` as a TOML value.
+trait Strategy {
+    /// Returns the value written for `Possible::None`.
+    fn null() -> Value;
+
+    /// Returns whether `value` represents `Possible::None`, failing if it is ambiguous.
+    fn is_null(value: &Value) -> Result<bool, String>;
+}
+
+fn to_value<N, T>(value: &Possible<T>) -> Result<Value, String>
+where
+    N: Strategy,
+    T: Serialize,
+{
+    match value {
+        Possible::Some(value) => {
+            let value = Value::try_from(value).map_err(|error| error.to_string())?;
+
+            if N::is_null(&value)? {
+                Err(format!(
+                    "`Possible::Some` value {} is ambiguous with `Possible::None`",
+                    value
+                ))
+            } else {
+                Ok(value)
+            }
+        }
+        Possible::None => Ok(N::null()),
+        Possible::Void => Err(String::from(
+            "`Possible::Void` cannot be written to TOML, \
+             skip it with `skip_serializing_if = \"Possible::is_void\"`",
+        )),
+    }
+}
+
+fn from_value<N, T>(value: Value) -> Result<Possible<T>, String>
+where
+    N: Strategy,
+    T: DeserializeOwned,
+{
+    if N::is_null(&value)? {
+        Ok(Possible::None)
+    } else {
+        T::deserialize(value)
+            .map(Possible::Some)
+            .map_err(|error| error.to_string())
+    }
+}
+
+fn serialize<N, T, S>(value: &Possible<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    N: Strategy,
+    T: Serialize,
+    S: Serializer,
+{
+    use serde::ser::Error;
+
+    to_value::<N, T>(value)
+        .map_err(S::Error::custom)?
+        .serialize(serializer)
+}
+
+fn deserialize<'de, N, T, D>(deserializer: D) -> Result<Possible<T>, D::Error>
+where
+    N: Strategy,
+    T: DeserializeOwned,
+    D: Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    from_value::<N, T>(Value::deserialize(deserializer)?).map_err(D::Error::custom)
+}
+
+fn serialize_seq<N, T, S>(values: &[Possible<T>], serializer: S) -> Result<S::Ok, S::Error>
+where
+    N: Strategy,
+    T: Serialize,
+    S: Serializer,
+{
+    use serde::ser::Error;
+
+    let values = values
+        .iter()
+        .map(|value| match value {
+            Possible::Void => Err(String::from(
+                "`Possible::Void` cannot be written as an element of a TOML array",
+            )),
+            value => to_value::<N, T>(value),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(S::Error::custom)?;
+
+    values.serialize(serializer)
+}
+
+fn deserialize_seq<'de, N, T, D>(deserializer: D) -> Result<Vec<Possible<T>>, D::Error>
+where
+    N: Strategy,
+    T: DeserializeOwned,
+    D: Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    Vec::<Value>::deserialize(deserializer)?
+        .into_iter()
+        .map(from_value::<N, T>)
+        .collect::<Result<_, _>>()
+        .map_err(D::Error::custom)
+}
@@ -0,0 +1,118 @@
+//! Represents `Possible::None` in TOML as the table `{ null = true }`.
+//!
+//! Unlike a sentinel string, the marker cannot collide with string values. A table with a
+//! `null` key and any other content is rejected as ambiguous, as is a `Possible::Some`
+//! value that serializes as the marker.
+//!
+//! # Examples
+//!
+//! ```
+//! use possible::Possible;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize, PartialEq)]
+//! struct Config {
+//!     #[serde(default, skip_serializing_if = "Possible::is_void")]
+//!     #[serde(with = "possible::repr::toml::marker")]
+//!     proxy: Possible<String>,
+//! }
+//!
+//! let parsed: Config = toml::from_str("proxy = { null = true }").unwrap();
+//! assert_eq!(parsed, Config { proxy: Possible::None });
+//!
+//! let parsed = toml::from_str::<Config>("proxy = { null = true, host = \"localhost\" }");
+//! assert!(parsed.is_err());
+//! ```
+
+use super::{super::super::Possible, Strategy};
+use serde::{de::DeserializeOwned, Deserializer, Serialize, Serializer};
+use toml::{value::Table, Value};
+
+/// The key of the marker table.
+pub const KEY: &str = "null";
+
+struct Marker;
+
+impl Strategy for Marker {
+    fn null() -> Value {
+        let mut table = Table::new();
+        table.insert(String::from(KEY), Value::Boolean(true));
+        Value::Table(table)
+    }
+
+    fn is_null(value: &Value) -> Result<bool, String> {
+        match value {
+            Value::Table(table) if table.contains_key(KEY) => {
+                if table.len() == 1 && table.get(KEY) == Some(&Value::Boolean(true)) {
+                    Ok(true)
+                } else {
+                    Err(format!(
+                        "table {} is ambiguous, expected exactly `{{ {} = true }}`",
+                        value, KEY
+                    ))
+                }
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
+/// Serializes a `Possible`, writing `Possible::None` as `{ null = true }`.
+///
+/// # Errors
+///
+/// Fails for `Possible::Void`, and for a `Possible::Some` that serializes as a table with
+/// a `null` key.
+pub fn serialize<T, S>(value: &Possible<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    super::serialize::<Marker, T, S>(value, serializer)
+}
+
+/// Deserializes a `Possible`, reading `{ null = true }` as `Possible::None`.
+///
+/// # Errors
+///
+/// Fails for a table with a `null` key that is not exactly `{ null = true }`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Possible<T>, D::Error>
+where
+    T: DeserializeOwned,
+    D: Deserializer<'de>,
+{
+    super::deserialize::<Marker, T, D>(deserializer)
+}
+
+/// Represents the elements of a `Vec<Possible<T>>` like [`marker`](super).
+pub mod seq {
+    use super::{super::super::super::Possible, Marker};
+    use serde::{de::DeserializeOwned, Deserializer, Serialize, Serializer};
+
+    /// Serializes the elements, writing `Possible::None` as `{ null = true }`.
+    ///
+    /// # Errors
+    ///
+    /// Fails for `Possible::Void` elements, and for `Possible::Some` elements that serialize
+    /// as a table with a `null` key.
+    pub fn serialize<T, S>(values: &[Possible<T>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        super::super::serialize_seq::<Marker, T, S>(values, serializer)
+    }
+
+    /// Deserializes the elements, reading `{ null = true }` as `Possible::None`.
+    ///
+    /// # Errors
+    ///
+    /// Fails for a table with a `null` key that is not exactly `{ null = true }`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<Possible<T>>, D::Error>
+    where
+        T: DeserializeOwned,
+        D: Deserializer<'de>,
+    {
+        super::super::deserialize_seq::<Marker, T, D>(deserializer)
+    }
+}
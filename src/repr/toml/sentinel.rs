@@ -0,0 +1,164 @@
+//! Represents `Possible::None` in TOML as a reserved string.
+//!
+//! The default string is [`Null::VALUE`]. Another string can be used by implementing
+//! [`Sentinel`] and passing it to the `*_with` functions:
+//!
+//! ```
+//! use possible::{repr::toml::sentinel::Sentinel, Possible};
+//! use serde::{Deserialize, Serialize};
+//!
+//! struct Nil;
+//!
+//! impl Sentinel for Nil {
+//!     const VALUE: &'static str = "nil";
+//! }
+//!
+//! #[derive(Debug, Serialize, Deserialize, PartialEq)]
+//! struct Config {
+//!     #[serde(default, skip_serializing_if = "Possible::is_void")]
+//!     #[serde(serialize_with = "possible::repr::toml::sentinel::serialize_with::<Nil, _, _>")]
+//!     #[serde(deserialize_with = "possible::repr::toml::sentinel::deserialize_with::<Nil, _, _>")]
+//!     proxy: Possible<String>,
+//! }
+//!
+//! let config = Config { proxy: Possible::None };
+//! let serialized = toml::to_string(&config).unwrap();
+//! assert_eq!(serialized, "proxy = \"nil\"\n");
+//!
+//! let parsed: Config = toml::from_str(&serialized).unwrap();
+//! assert_eq!(parsed, config);
+//!
+//! let config = Config { proxy: Possible::Some(String::from("nil")) };
+//! assert!(toml::to_string(&config).is_err());
+//! ```
+
+use super::{super::super::Possible, Strategy};
+use serde::{de::DeserializeOwned, Deserializer, Serialize, Serializer};
+use std::marker::PhantomData;
+use toml::Value;
+
+/// A string reserved to represent `Possible::None`.
+pub trait Sentinel {
+    /// The reserved string.
+    const VALUE: &'static str;
+}
+
+/// The default sentinel, `"__null__"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Null;
+
+impl Sentinel for Null {
+    const VALUE: &'static str = "__null__";
+}
+
+struct Reserved<N>(PhantomData<N>);
+
+impl<N: Sentinel> Strategy for Reserved<N> {
+    fn null() -> Value {
+        Value::String(String::from(N::VALUE))
+    }
+
+    fn is_null(value: &Value) -> Result<bool, String> {
+        Ok(value.as_str() == Some(N::VALUE))
+    }
+}
+
+/// Serializes a `Possible`, writing `Possible::None` as [`Null::VALUE`].
+///
+/// # Errors
+///
+/// Fails for `Possible::Void`, and for a `Possible::Some` that serializes as the sentinel.
+pub fn serialize<T, S>(value: &Possible<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    serialize_with::<Null, T, S>(value, serializer)
+}
+
+/// Deserializes a `Possible`, reading [`Null::VALUE`] as `Possible::None`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Possible<T>, D::Error>
+where
+    T: DeserializeOwned,
+    D: Deserializer<'de>,
+{
+    deserialize_with::<Null, T, D>(deserializer)
+}
+
+/// Serializes a `Possible`, writing `Possible::None` as the sentinel `N`.
+///
+/// # Errors
+///
+/// Fails for `Possible::Void`, and for a `Possible::Some` that serializes as the sentinel.
+pub fn serialize_with<N, T, S>(value: &Possible<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    N: Sentinel,
+    T: Serialize,
+    S: Serializer,
+{
+    super::serialize::<Reserved<N>, T, S>(value, serializer)
+}
+
+/// Deserializes a `Possible`, reading the sentinel `N` as `Possible::None`.
+pub fn deserialize_with<'de, N, T, D>(deserializer: D) -> Result<Possible<T>, D::Error>
+where
+    N: Sentinel,
+    T: DeserializeOwned,
+    D: Deserializer<'de>,
+{
+    super::deserialize::<Reserved<N>, T, D>(deserializer)
+}
+
+/// Represents the elements of a `Vec<Possible<T>>` like [`sentinel`](super).
+pub mod seq {
+    use super::{super::super::super::Possible, Null, Reserved, Sentinel};
+    use serde::{de::DeserializeOwned, Deserializer, Serialize, Serializer};
+
+    /// Serializes the elements, writing `Possible::None` as [`Null::VALUE`].
+    ///
+    /// # Errors
+    ///
+    /// Fails for `Possible::Void` elements, and for `Possible::Some` elements that serialize
+    /// as the sentinel.
+    pub fn serialize<T, S>(values: &[Possible<T>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        serialize_with::<Null, T, S>(values, serializer)
+    }
+
+    /// Deserializes the elements, reading [`Null::VALUE`] as `Possible::None`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<Possible<T>>, D::Error>
+    where
+        T: DeserializeOwned,
+        D: Deserializer<'de>,
+    {
+        deserialize_with::<Null, T, D>(deserializer)
+    }
+
+    /// Serializes the elements, writing `Possible::None` as the sentinel `N`.
+    ///
+    /// # Errors
+    ///
+    /// Fails for `Possible::Void` elements, and for `Possible::Some` elements that serialize
+    /// as the sentinel.
+    pub fn serialize_with<N, T, S>(values: &[Possible<T>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        N: Sentinel,
+        T: Serialize,
+        S: Serializer,
+    {
+        super::super::serialize_seq::<Reserved<N>, T, S>(values, serializer)
+    }
+
+    /// Deserializes the elements, reading the sentinel `N` as `Possible::None`.
+    pub fn deserialize_with<'de, N, T, D>(deserializer: D) -> Result<Vec<Possible<T>>, D::Error>
+    where
+        N: Sentinel,
+        T: DeserializeOwned,
+        D: Deserializer<'de>,
+    {
+        super::super::deserialize_seq::<Reserved<N>, T, D>(deserializer)
+    }
+}
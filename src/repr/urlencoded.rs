@@ -0,0 +1,459 @@
+//! Reads and writes URL query strings and HTML forms with sequences as repeated keys.
+//!
+//! `serde_urlencoded` has no representation for sequences, so a `Vec` field can neither be
+//! read from nor written to a form such as `tags=a&tags=b`. The [`from_str`] and
+//! [`to_string`] functions in here parse and write the pairs of the form with
+//! `serde_urlencoded`, and group the values of a repeated key, or of a key ending in `[]`,
+//! into a sequence. Together with [`as_query::seq`](super::as_query::seq), this
+//! represents every state of a `Possible<Vec<T>>`.
+//!
+//! # Examples
+//!
+//! ```
+//! use possible::{repr::urlencoded, Possible};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize, PartialEq)]
+//! struct Form {
+//!     #[serde(default, skip_serializing_if = "Possible::is_void")]
+//!     #[serde(with = "possible::repr::as_query::seq")]
+//!     tags: Possible<Vec<String>>,
+//!     #[serde(default, skip_serializing_if = "Possible::is_void")]
+//!     #[serde(with = "possible::repr::as_query::seq")]
+//!     ids: Possible<Vec<u32>>,
+//! }
+//!
+//! let form = Form {
+//!     tags: Possible::Some(vec![String::from("a"), String::from("b")]),
+//!     ids: Possible::None,
+//! };
+//!
+//! let serialized = urlencoded::to_string(&form).unwrap();
+//! assert_eq!(serialized, "tags=a&tags=b&ids=");
+//! assert_eq!(urlencoded::from_str::<Form>(&serialized).unwrap(), form);
+//!
+//! let parsed: Form = urlencoded::from_str("ids[]=7").unwrap();
+//! assert_eq!(parsed.ids, Possible::Some(vec![7]));
+//! ```
+
+use super::text::{to_text, TextDeserializer};
+use serde::{
+    de::{
+        self,
+        value::{MapDeserializer, SeqDeserializer},
+        DeserializeOwned, IntoDeserializer, Visitor,
+    },
+    forward_to_deserialize_any,
+    ser::{self, Impossible, Serialize},
+};
+use serde_urlencoded::ser::Error;
+use std::{
+    borrow::Cow,
+    collections::hash_map::{self, HashMap},
+    marker::PhantomData,
+};
+
+/// Deserializes a `T` from a query string, reading repeated keys and keys ending in `[]`
+/// as sequences.
+///
+/// # Errors
+///
+/// Fails if the query string cannot be decoded, or does not match the fields of `T`.
+pub fn from_str<T: DeserializeOwned>(input: &str) -> Result<T, de::value::Error> {
+    let mut entries: Vec<(String, Entry)> = Vec::new();
+    // the position of each key in `entries`, which keeps the order of the query string
+    let mut positions: HashMap<String, usize> = HashMap::new();
+
+    for (key, value) in serde_urlencoded::from_str::<Vec<(String, String)>>(input)? {
+        let (key, bracketed) = match key.strip_suffix("[]") {
+            Some(key) => (String::from(key), true),
+            None => (key, false),
+        };
+
+        match positions.entry(key) {
+            hash_map::Entry::Occupied(position) => entries[*position.get()].1.push(value),
+            hash_map::Entry::Vacant(position) => {
+                let entry = if bracketed {
+                    Entry::Many(vec![value])
+                } else {
+                    Entry::One(value)
+                };
+                entries.push((position.key().clone(), entry));
+                position.insert(entries.len() - 1);
+            }
+        }
+    }
+
+    T::deserialize(MapDeserializer::new(entries.into_iter()))
+}
+
+/// Serializes a struct or map as a query string, writing sequences as repeated keys.
+///
+/// # Errors
+///
+/// Fails if `value` is not a struct or map, or has values that are neither scalars nor
+/// sequences of scalars.
+pub fn to_string<T: Serialize + ?Sized>(value: &T) -> Result<String, Error> {
+    let mut pairs = Vec::new();
+    value.serialize(Pairs {
+        key: None,
+        pairs: &mut pairs,
+    })?;
+
+    serde_urlencoded::to_string(pairs)
+}
+
+/// The values of a key in a query string.
+enum Entry {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Entry {
+    fn push(&mut self, value: String) {
+        match self {
+            Entry::One(first) => *self = Entry::Many(vec![std::mem::take(first), value]),
+            Entry::Many(values) => values.push(value),
+        }
+    }
+}
+
+impl<'de, E: de::Error> IntoDeserializer<'de, E> for Entry {
+    type Deserializer = EntryDeserializer<E>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        EntryDeserializer {
+            entry: self,
+            marker: PhantomData,
+        }
+    }
+}
+
+struct EntryDeserializer<E> {
+    entry: Entry,
+    marker: PhantomData<E>,
+}
+
+fn text<'de, E>(value: String) -> TextDeserializer<'de, E> {
+    TextDeserializer::new(Cow::Owned(value))
+}
+
+fn many<'de, V, E>(values: Vec<String>, visitor: V) -> Result<V::Value, E>
+where
+    V: Visitor<'de>,
+    E: de::Error,
+{
+    use serde::Deserializer;
+
+    SeqDeserializer::new(values.into_iter().map(text)).deserialize_any(visitor)
+}
+
+macro_rules! deserialize_entry {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+                match self.entry {
+                    Entry::One(value) => text(value).$method(visitor),
+                    Entry::Many(values) => many(values, visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de, E: de::Error> de::Deserializer<'de> for EntryDeserializer<E> {
+    type Error = E;
+
+    deserialize_entry! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32
+        deserialize_i64 deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32
+        deserialize_u64 deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char
+        deserialize_seq
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        match self.entry {
+            Entry::One(value) => text(value).deserialize_enum(name, variants, visitor),
+            Entry::Many(values) => many(values, visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+/// Serializer that collects the pairs of a query string, where `key` is the key of the
+/// value being serialized, or `None` for the struct or map of the whole query string.
+struct Pairs<'a> {
+    key: Option<&'a str>,
+    pairs: &'a mut Vec<(String, String)>,
+}
+
+impl Pairs<'_> {
+    fn push(self, value: String) -> Result<(), Error> {
+        match self.key {
+            Some(key) => {
+                self.pairs.push((String::from(key), value));
+                Ok(())
+            }
+            None => Err(unsupported()),
+        }
+    }
+}
+
+fn unsupported() -> Error {
+    ser::Error::custom(
+        "only structs and maps of scalars or sequences can be written as a query string",
+    )
+}
+
+macro_rules! serialize_text {
+    ($($method:ident: $ty:ty,)*) => {
+        $(
+            fn $method(self, value: $ty) -> Result<(), Error> {
+                self.push(value.to_string())
+            }
+        )*
+    };
+}
+
+impl<'a> ser::Serializer for Pairs<'a> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Elements<'a>;
+    type SerializeTuple = Elements<'a>;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Fields<'a>;
+    type SerializeStruct = Fields<'a>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    serialize_text! {
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_i128: i128,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_u128: u128,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_char: char,
+        serialize_str: &str,
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_none(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<(), Error> {
+        self.push(String::from(variant))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        match self.key {
+            Some(key) => Ok(Elements {
+                key,
+                pairs: self.pairs,
+            }),
+            None => Err(unsupported()),
+        }
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        match self.key {
+            Some(_) => Err(unsupported()),
+            None => Ok(Fields {
+                key: None,
+                pairs: self.pairs,
+            }),
+        }
+    }
+
+    fn serialize_struct(self, _: &'static str, len: usize) -> Result<Self::SerializeStruct, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Err(unsupported())
+    }
+}
+
+/// The elements of a sequence, each written with the key of the sequence.
+struct Elements<'a> {
+    key: &'a str,
+    pairs: &'a mut Vec<(String, String)>,
+}
+
+impl ser::SerializeSeq for Elements<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        match to_text(value) {
+            Some(text) => {
+                self.pairs.push((String::from(self.key), text));
+                Ok(())
+            }
+            None => Err(unsupported()),
+        }
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for Elements<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// The fields of the struct or map of a whole query string.
+struct Fields<'a> {
+    key: Option<String>,
+    pairs: &'a mut Vec<(String, String)>,
+}
+
+impl Fields<'_> {
+    fn field<T: Serialize + ?Sized>(&mut self, key: &str, value: &T) -> Result<(), Error> {
+        value.serialize(Pairs {
+            key: Some(key),
+            pairs: self.pairs,
+        })
+    }
+}
+
+impl ser::SerializeMap for Fields<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(to_text(key).ok_or_else(unsupported)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self.key.take().ok_or_else(unsupported)?;
+        self.field(&key, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for Fields<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+//! Schema generation support for `Possible`, behind the `schemars` and `utoipa` features.
+//!
+//! A `Possible<T>` is described by the schema of `T` with `null` allowed. Since a missing
+//! field is `Possible::Void` only with `serde(default)`, a `Possible` property is listed in
+//! the `required` properties of its parent like any other property unless it has the
+//! `serde(default)` attribute, as added by `#[possible::fields]` along with
+//! `serde(skip_serializing_if = "Possible::is_void")`. The `schemars` derive also reads
+//! `#[schemars(default)]`, and the `utoipa` derive `serde(skip_serializing_if)` or
+//! `#[schema(required = false)]`.
+//!
+//! The `utoipa` derives only describe generic field types through a hidden trait, so a
+//! `Possible` field names its schema with
+//! `#[schema(schema_with = <Possible<T> as PartialSchema>::schema)]`, or
+//! `#[param(schema_with = …)]` for `IntoParams`. As a component, `Possible<T>` is named
+//! `Possible_` followed by the name of `T`.
+
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "utoipa")]
+mod utoipa;
@@ -0,0 +1,53 @@
+use crate::Possible;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use serde_json::Value;
+use std::borrow::Cow;
+
+impl<T: JsonSchema> JsonSchema for Possible<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("Nullable_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("Possible<{}>", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        allow_null(generator.subschema_for::<T>())
+    }
+}
+
+/// Allows `null` in `schema`, by adding it to the `type` of a schema that only restricts its
+/// type, or as an alternative in an `anyOf` otherwise.
+fn allow_null(mut schema: Schema) -> Schema {
+    if schema.as_bool() == Some(true) {
+        return schema;
+    }
+
+    let restricted = ["$ref", "allOf", "anyOf", "oneOf", "if", "const", "enum"]
+        .iter()
+        .any(|keyword| schema.get(*keyword).is_some());
+
+    match schema.get_mut("type") {
+        Some(Value::Array(types)) if !restricted => {
+            if !types.iter().any(|ty| ty == "null") {
+                types.push(Value::from("null"));
+            }
+            schema
+        }
+        Some(Value::String(ty)) if !restricted => {
+            if ty != "null" {
+                let ty = core::mem::take(ty);
+                schema.insert(String::from("type"), Value::from(vec![ty, String::from("null")]));
+            }
+            schema
+        }
+        _ => json_schema!({
+            "anyOf": [schema, { "type": "null" }]
+        }),
+    }
+}
@@ -0,0 +1,60 @@
+use crate::Possible;
+use std::borrow::Cow;
+use utoipa::{
+    openapi::{
+        schema::{ObjectBuilder, OneOfBuilder, Schema, SchemaType, Type},
+        RefOr,
+    },
+    PartialSchema, ToSchema,
+};
+
+impl<T: PartialSchema> PartialSchema for Possible<T> {
+    fn schema() -> RefOr<Schema> {
+        nullable(T::schema())
+    }
+}
+
+impl<T: ToSchema> ToSchema for Possible<T> {
+    // a distinct name per `T`, since the schema of `Possible<T>` differs from the one of `T`
+    fn name() -> Cow<'static, str> {
+        format!("Possible_{}", T::name()).into()
+    }
+
+    fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+        T::schemas(schemas);
+    }
+}
+
+/// Allows `null` in `schema`, as an OpenAPI 3.1 list of types where possible.
+fn nullable(schema: RefOr<Schema>) -> RefOr<Schema> {
+    match schema {
+        RefOr::T(Schema::Object(mut object)) => {
+            allow_null(&mut object.schema_type);
+            RefOr::T(Schema::Object(object))
+        }
+        RefOr::T(Schema::Array(mut array)) => {
+            allow_null(&mut array.schema_type);
+            RefOr::T(Schema::Array(array))
+        }
+        schema => OneOfBuilder::new()
+            .item(ObjectBuilder::new().schema_type(Type::Null))
+            .item(schema)
+            .into(),
+    }
+}
+
+fn allow_null(schema_type: &mut SchemaType) {
+    match schema_type {
+        SchemaType::Type(Type::Null) => {}
+        SchemaType::Type(single) => {
+            *schema_type = SchemaType::Array(vec![single.clone(), Type::Null])
+        }
+        SchemaType::Array(types) => {
+            if !types.contains(&Type::Null) {
+                types.push(Type::Null);
+            }
+        }
+        // a schema without a type already accepts `null`
+        SchemaType::AnyValue => {}
+    }
+}
@@ -0,0 +1,34 @@
+//! SQL support for `Possible`, behind the `sqlx`, `diesel`, and `sea-orm` features.
+//!
+//! `Possible<T>` can be bound and read like an `Option<T>`: `Possible::Some` encodes its
+//! value and `Possible::None` encodes SQL `NULL`, while a `NULL` column decodes as
+//! `Possible::None`. `Possible::Void` has no SQL value, so encoding it is an error.
+//! Instead, `Possible::Void` columns are left out of a query entirely, so that an
+//! `UPDATE` keeps their current values and an `INSERT` uses their column defaults.
+//!
+//! - With the `sqlx` feature, `Possible<T>` implements the
+//!   [`sqlx`](https://docs.rs/sqlx) `Encode`, `Decode`, and `Type` traits, and
+//!   `Columns` builds the columns of a query with a `QueryBuilder`.
+//! - With the `diesel` feature, `Possible<T>` implements the
+//!   [`diesel`](https://docs.rs/diesel) `ToSql`, `FromSql`, and `AsExpression` traits for
+//!   nullable columns, and the `AsChangeset` and `Insertable` derives of this module
+//!   accept `Possible` fields, which [`assign`] turns into column assignments.
+//! - With the `sea-orm` feature, `Possible<T>` converts to and from a
+//!   [`sea-orm`](https://docs.rs/sea-orm) `ActiveValue<Option<T>>`, where
+//!   `Possible::Void` is `NotSet`, and implements [`Patch`](crate::Patch) for
+//!   `ActiveValue` fields. `#[patch(active_model = "ActiveModel")]` on a
+//!   `#[derive(Patch)]` struct applies its patch struct to an `ActiveModel`.
+
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "sea-orm")]
+mod sea_orm;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+
+#[cfg(feature = "diesel")]
+pub use self::diesel::{assign, Bound};
+#[cfg(feature = "sqlx")]
+pub use self::sqlx::Columns;
+#[cfg(feature = "diesel")]
+pub use possible_derive::{AsChangeset, Insertable};
@@ -0,0 +1,176 @@
+use crate::Possible;
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql, Queryable},
+    dsl,
+    expression::{
+        is_aggregate, AppearsOnTable, AsExpression, Expression, SelectableExpression,
+        TypedExpressionType, ValidGrouping,
+    },
+    query_builder::{AstPass, QueryFragment, QueryId},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::{is_nullable, HasSqlType, Nullable, SingleValue, SqlType},
+    Column, ExpressionMethods, QueryResult,
+};
+use std::marker::PhantomData;
+
+impl<T, ST, DB> ToSql<Nullable<ST>, DB> for Possible<T>
+where
+    T: ToSql<ST, DB>,
+    DB: Backend,
+    ST: SqlType<IsNull = is_nullable::NotNull>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        match self {
+            Possible::Some(value) => value.to_sql(out),
+            Possible::None => Ok(IsNull::Yes),
+            Possible::Void => Err(VOID.into()),
+        }
+    }
+}
+
+impl<T, ST, DB> FromSql<Nullable<ST>, DB> for Possible<T>
+where
+    T: FromSql<ST, DB>,
+    DB: Backend,
+    ST: SqlType<IsNull = is_nullable::NotNull>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        T::from_sql(bytes).map(Possible::Some)
+    }
+
+    fn from_nullable_sql(bytes: Option<DB::RawValue<'_>>) -> deserialize::Result<Self> {
+        match bytes {
+            Some(bytes) => T::from_sql(bytes).map(Possible::Some),
+            None => Ok(Possible::None),
+        }
+    }
+}
+
+impl<T, ST> AsExpression<Nullable<ST>> for Possible<T>
+where
+    ST: SqlType<IsNull = is_nullable::NotNull>,
+    Nullable<ST>: TypedExpressionType,
+{
+    type Expression = Bound<Nullable<ST>, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound {
+            value: self,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, ST> AsExpression<Nullable<ST>> for &Possible<T>
+where
+    ST: SqlType<IsNull = is_nullable::NotNull>,
+    Nullable<ST>: TypedExpressionType,
+{
+    type Expression = Bound<Nullable<ST>, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound {
+            value: self,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, ST, DB> Queryable<ST, DB> for Possible<T>
+where
+    ST: SingleValue<IsNull = is_nullable::IsNullable>,
+    DB: Backend,
+    Self: FromSql<ST, DB>,
+{
+    type Row = Self;
+
+    fn build(row: Self::Row) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
+
+/// A `Possible` value bound as a query parameter of the SQL type `ST`, which is the
+/// expression of a `Possible` in a diesel query.
+#[derive(Debug, Clone, Copy)]
+pub struct Bound<ST, T> {
+    value: T,
+    marker: PhantomData<ST>,
+}
+
+impl<ST, T> Expression for Bound<ST, T>
+where
+    ST: SqlType + TypedExpressionType,
+{
+    type SqlType = ST;
+}
+
+impl<ST, T, DB> QueryFragment<DB> for Bound<ST, T>
+where
+    DB: Backend + HasSqlType<ST>,
+    T: ToSql<ST, DB>,
+{
+    fn walk_ast<'b>(&'b self, mut pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        pass.push_bind_param(&self.value)
+    }
+}
+
+impl<ST: QueryId, T> QueryId for Bound<ST, T> {
+    type QueryId = Bound<ST::QueryId, ()>;
+
+    const HAS_STATIC_QUERY_ID: bool = ST::HAS_STATIC_QUERY_ID;
+}
+
+impl<ST, T, QS> SelectableExpression<QS> for Bound<ST, T> where Bound<ST, T>: AppearsOnTable<QS> {}
+
+impl<ST, T, QS> AppearsOnTable<QS> for Bound<ST, T> where Bound<ST, T>: Expression {}
+
+impl<ST, T, GB> ValidGrouping<GB> for Bound<ST, T> {
+    type IsAggregate = is_aggregate::Never;
+}
+
+const VOID: &str = "`Possible::Void` cannot be encoded, leave the column out of the query instead";
+
+/// Assigns `value` to `column` unless it is `Possible::Void`.
+///
+/// `Possible::None` assigns `NULL`. The result can be passed to `set` on its own or in a
+/// tuple with other assignments, where `None` leaves the column out of the changeset the
+/// same way diesel treats `None` for an `Option` field of an `AsChangeset` struct. It can
+/// likewise be passed to `values` of an `INSERT`, where `None` writes the column default.
+///
+/// # Examples
+///
+/// ```
+/// use diesel::{debug_query, prelude::*, sqlite::Sqlite};
+/// use possible::{sql::assign, Possible};
+///
+/// diesel::table! {
+///     users {
+///         id -> Integer,
+///         name -> Nullable<Text>,
+///         email -> Nullable<Text>,
+///     }
+/// }
+///
+/// let name: Possible<String> = Possible::Void;
+/// let email: Possible<String> = Possible::None;
+///
+/// let query = diesel::update(users::table.find(7))
+///     .set((assign(users::name, name), assign(users::email, email)));
+///
+/// assert_eq!(
+///     debug_query::<Sqlite, _>(&query).to_string(),
+///     "UPDATE `users` SET `email` = ? WHERE (`users`.`id` = ?) -- binds: [None, 7]",
+/// );
+/// ```
+pub fn assign<C, T>(column: C, value: Possible<T>) -> Option<dsl::Eq<C, Possible<T>>>
+where
+    C: Column + ExpressionMethods,
+    C::SqlType: SqlType,
+    Possible<T>: AsExpression<C::SqlType>,
+{
+    match value {
+        Possible::Void => None,
+        value => Some(column.eq(value)),
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{Patch, PatchError, Possible};
+use sea_orm::{ActiveValue, Value};
+
+impl<T> From<Possible<T>> for ActiveValue<Option<T>>
+where
+    Option<T>: Into<Value>,
+{
+    /// Converts `Possible::Void` to `NotSet`, and other values to `Set`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::Possible;
+    /// use sea_orm::ActiveValue;
+    ///
+    /// let value: ActiveValue<Option<i32>> = Possible::None.into();
+    /// assert_eq!(value, ActiveValue::Set(None));
+    ///
+    /// let value: ActiveValue<Option<i32>> = Possible::Void.into();
+    /// assert_eq!(value, ActiveValue::NotSet);
+    /// ```
+    fn from(value: Possible<T>) -> Self {
+        match value {
+            Possible::Some(value) => ActiveValue::Set(Some(value)),
+            Possible::None => ActiveValue::Set(None),
+            Possible::Void => ActiveValue::NotSet,
+        }
+    }
+}
+
+impl<T> From<ActiveValue<Option<T>>> for Possible<T>
+where
+    Option<T>: Into<Value>,
+{
+    /// Converts `NotSet` to `Possible::Void`, and `Set` or `Unchanged` values to
+    /// `Possible::Some` or `Possible::None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::Possible;
+    /// use sea_orm::ActiveValue;
+    ///
+    /// let value: Possible<i32> = ActiveValue::Unchanged(Some(7)).into();
+    /// assert_eq!(value, Possible::Some(7));
+    ///
+    /// let value: Possible<i32> = ActiveValue::NotSet.into();
+    /// assert_eq!(value, Possible::Void);
+    /// ```
+    fn from(value: ActiveValue<Option<T>>) -> Self {
+        match value {
+            ActiveValue::Set(value) | ActiveValue::Unchanged(value) => Possible::from(value),
+            ActiveValue::NotSet => Possible::Void,
+        }
+    }
+}
+
+impl<T> Patch<ActiveValue<Option<T>>> for Possible<T>
+where
+    Option<T>: Into<Value>,
+{
+    fn apply(self, target: &mut ActiveValue<Option<T>>) -> Result<(), PatchError> {
+        match self {
+            Possible::Some(value) => *target = ActiveValue::Set(Some(value)),
+            Possible::None => *target = ActiveValue::Set(None),
+            Possible::Void => {}
+        }
+
+        Ok(())
+    }
+
+    fn check(&self, _target: &ActiveValue<Option<T>>) -> Result<(), PatchError> {
+        Ok(())
+    }
+}
+
+impl<T> Patch<ActiveValue<T>> for Possible<T>
+where
+    T: Into<Value>,
+{
+    fn apply(self, target: &mut ActiveValue<T>) -> Result<(), PatchError> {
+        match self {
+            Possible::Some(value) => *target = ActiveValue::Set(value),
+            Possible::None => return Err(PatchError::NotNullable),
+            Possible::Void => {}
+        }
+
+        Ok(())
+    }
+
+    fn check(&self, _target: &ActiveValue<T>) -> Result<(), PatchError> {
+        match self {
+            Possible::None => Err(PatchError::NotNullable),
+            Possible::Some(_) | Possible::Void => Ok(()),
+        }
+    }
+}
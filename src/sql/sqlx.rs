@@ -0,0 +1,218 @@
+use crate::Possible;
+use sqlx::{
+    database::Database,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    Decode, QueryBuilder, Type, ValueRef,
+};
+
+impl<T, DB> Type<DB> for Possible<T>
+where
+    T: Type<DB>,
+    DB: Database,
+{
+    fn type_info() -> DB::TypeInfo {
+        T::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Option<T> as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, T, DB> Encode<'q, DB> for Possible<T>
+where
+    T: Encode<'q, DB> + Type<DB>,
+    DB: Database,
+{
+    fn encode(self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        match self {
+            Possible::Some(value) => value.encode(buf),
+            Possible::None => Ok(IsNull::Yes),
+            Possible::Void => Err(VOID.into()),
+        }
+    }
+
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        match self {
+            Possible::Some(value) => value.encode_by_ref(buf),
+            Possible::None => Ok(IsNull::Yes),
+            Possible::Void => Err(VOID.into()),
+        }
+    }
+
+    fn produces(&self) -> Option<DB::TypeInfo> {
+        match self {
+            Possible::Some(value) => value.produces(),
+            Possible::None | Possible::Void => Some(T::type_info()),
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        match self {
+            Possible::Some(value) => value.size_hint(),
+            Possible::None | Possible::Void => 0,
+        }
+    }
+}
+
+impl<'r, T, DB> Decode<'r, DB> for Possible<T>
+where
+    T: Decode<'r, DB>,
+    DB: Database,
+{
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        if value.is_null() {
+            Ok(Possible::None)
+        } else {
+            T::decode(value).map(Possible::Some)
+        }
+    }
+}
+
+const VOID: &str = "`Possible::Void` cannot be encoded, leave the column out of the query instead";
+
+type Bind<'args, DB> = Box<dyn FnOnce(&mut QueryBuilder<'args, DB>) + Send + 'args>;
+
+/// Column values for an `UPDATE` or `INSERT` built with a [`QueryBuilder`], where
+/// `Possible::Void` columns are left out.
+///
+/// Column names are written into the query as they are, so they must be trusted identifiers
+/// rather than user input.
+///
+/// # Examples
+///
+/// ```
+/// use possible::{sql::Columns, Possible};
+/// use sqlx::{QueryBuilder, Sqlite};
+///
+/// let mut columns = Columns::<Sqlite>::new();
+/// columns
+///     .push("name", Possible::Some("Ferris"))
+///     .push("email", Possible::<&str>::None)
+///     .push("age", Possible::<i64>::Void);
+///
+/// let mut query = columns
+///     .push_assignments(QueryBuilder::new("UPDATE users SET "))
+///     .expect("at least one column is set");
+/// query.push(" WHERE id = ").push_bind(7);
+///
+/// assert_eq!(query.sql(), "UPDATE users SET name = ?, email = ? WHERE id = ?");
+/// ```
+pub struct Columns<'args, DB: Database> {
+    columns: Vec<(String, Bind<'args, DB>)>,
+}
+
+impl<'args, DB: Database> Columns<'args, DB> {
+    /// Creates an empty set of columns.
+    pub fn new() -> Self {
+        Columns {
+            columns: Vec::new(),
+        }
+    }
+
+    /// Adds a column unless `value` is `Possible::Void`.
+    ///
+    /// `Possible::None` is bound as `NULL`.
+    pub fn push<T>(&mut self, column: impl Into<String>, value: Possible<T>) -> &mut Self
+    where
+        T: Encode<'args, DB> + Type<DB> + Send + 'args,
+    {
+        if !value.is_void() {
+            let bind: Bind<'args, DB> = Box::new(move |query| {
+                query.push_bind(value);
+            });
+            self.columns.push((column.into(), bind));
+        }
+
+        self
+    }
+
+    /// Returns the number of columns that will be written.
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Returns `true` if every pushed value was `Possible::Void`.
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// Writes the columns as `a = ?, b = ?` for the `SET` clause of an `UPDATE` into
+    /// `query`, and returns it to be completed and run.
+    ///
+    /// An `UPDATE` without any assignments is invalid SQL, so `query` is dropped and `None`
+    /// is returned when there are no columns, which leaves no query to run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::{sql::Columns, Possible};
+    /// use sqlx::{QueryBuilder, Sqlite};
+    ///
+    /// let mut columns = Columns::<Sqlite>::new();
+    /// columns.push("name", Possible::<&str>::Void);
+    ///
+    /// let query = columns.push_assignments(QueryBuilder::new("UPDATE users SET "));
+    /// assert!(query.is_none());
+    /// ```
+    pub fn push_assignments(
+        self,
+        mut query: QueryBuilder<'args, DB>,
+    ) -> Option<QueryBuilder<'args, DB>> {
+        if self.columns.is_empty() {
+            return None;
+        }
+
+        for (index, (column, bind)) in self.columns.into_iter().enumerate() {
+            if index > 0 {
+                query.push(", ");
+            }
+            query.push(column).push(" = ");
+            bind(&mut query);
+        }
+
+        Some(query)
+    }
+
+    /// Writes the columns as `(a, b) VALUES (?, ?)` for an `INSERT`, or as `DEFAULT VALUES`
+    /// when there are no columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use possible::{sql::Columns, Possible};
+    /// use sqlx::{QueryBuilder, Sqlite};
+    ///
+    /// let mut columns = Columns::<Sqlite>::new();
+    /// columns.push("name", Possible::<&str>::Void);
+    ///
+    /// let mut query = QueryBuilder::new("INSERT INTO users ");
+    /// columns.push_insert(&mut query);
+    ///
+    /// assert_eq!(query.sql(), "INSERT INTO users DEFAULT VALUES");
+    /// ```
+    pub fn push_insert(self, query: &mut QueryBuilder<'args, DB>) {
+        if self.columns.is_empty() {
+            query.push("DEFAULT VALUES");
+            return;
+        }
+
+        let (names, binds): (Vec<_>, Vec<_>) = self.columns.into_iter().unzip();
+
+        query.push("(").push(names.join(", ")).push(") VALUES (");
+        for (index, bind) in binds.into_iter().enumerate() {
+            if index > 0 {
+                query.push(", ");
+            }
+            bind(query);
+        }
+        query.push(")");
+    }
+}
+
+impl<DB: Database> Default for Columns<'_, DB> {
+    fn default() -> Self {
+        Columns::new()
+    }
+}
@@ -0,0 +1,28 @@
+//! Property testing and fuzzing support for `Possible`, behind the `proptest`,
+//! `quickcheck`, and `arbitrary` features.
+//!
+//! `Possible<T>` implements the `Arbitrary` trait of [`proptest`](https://docs.rs/proptest)
+//! and of [`quickcheck`](https://docs.rs/quickcheck) to generate all three states, where
+//! `Possible::Some` wraps an arbitrary `T`. Failing values shrink toward `Possible::Void`,
+//! then `Possible::None`, and then toward a simpler `Possible::Some`.
+//!
+//! The share of each state is set with [`Weights`], which [`strategy`](crate::strategy)
+//! takes together with the strategy of the inner value.
+//!
+//! For fuzzing, `Possible<T>` implements the `Arbitrary` trait of
+//! [`arbitrary`](https://docs.rs/arbitrary), which reads the state from one byte of the
+//! fuzzer input and then `T` for `Possible::Some`.
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+mod weights;
+
+#[cfg(feature = "proptest")]
+pub use self::proptest::{strategy, PossibleStrategy, PossibleValueTree};
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+pub use weights::Weights;
@@ -0,0 +1,31 @@
+use crate::Possible;
+use arbitrary::{size_hint, Arbitrary, MaxRecursionReached, Result, Unstructured};
+
+impl<'a, T> Arbitrary<'a> for Possible<T>
+where
+    T: Arbitrary<'a>,
+{
+    // exhausted input reads as zero, so it ends in `Possible::Void`
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=2u8)? {
+            0 => Possible::Void,
+            1 => Possible::None,
+            _ => Possible::Some(T::arbitrary(u)?),
+        })
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Self::try_size_hint(depth).unwrap_or_default()
+    }
+
+    #[inline]
+    fn try_size_hint(
+        depth: usize,
+    ) -> core::result::Result<(usize, Option<usize>), MaxRecursionReached> {
+        Ok(size_hint::and(
+            <u8 as Arbitrary>::try_size_hint(depth)?,
+            size_hint::or((0, Some(0)), T::try_size_hint(depth)?),
+        ))
+    }
+}
@@ -0,0 +1,162 @@
+use super::weights::Weights;
+use crate::Possible;
+use proptest::{
+    arbitrary::{any, any_with, Arbitrary},
+    strategy::{NewTree, Strategy, ValueTree},
+    test_runner::TestRunner,
+};
+use std::{collections::VecDeque, fmt};
+
+/// Creates a strategy that generates the three states of `Possible` as often as set by
+/// `weights`, where `Possible::Some` wraps a value of `inner`.
+///
+/// # Examples
+///
+/// ```
+/// use possible::{Possible, Weights};
+/// use proptest::{prelude::*, strategy::ValueTree, test_runner::TestRunner};
+///
+/// let strategy = possible::strategy(Weights::new(1, 0, 1), 0..10u8);
+///
+/// let mut runner = TestRunner::default();
+/// for _ in 0..100 {
+///     let value = strategy.new_tree(&mut runner).unwrap().current();
+///     assert_ne!(value, Possible::None);
+/// }
+/// ```
+///
+/// In a test:
+///
+/// ```
+/// use possible::{Possible, Weights};
+/// use proptest::prelude::*;
+///
+/// proptest!(|(value in possible::strategy(Weights::new(1, 0, 0), any::<u8>()))| {
+///     prop_assert_eq!(value.or(Possible::Void), value);
+/// });
+/// ```
+///
+/// # Panics
+///
+/// Generating a value panics if every weight is zero.
+pub fn strategy<S: Strategy>(weights: Weights, inner: S) -> PossibleStrategy<S> {
+    PossibleStrategy { weights, inner }
+}
+
+/// Strategy that generates `Possible` values, created with [`strategy`].
+#[derive(Clone)]
+#[must_use = "strategies do nothing unless used"]
+pub struct PossibleStrategy<S> {
+    weights: Weights,
+    inner: S,
+}
+
+impl<S: fmt::Debug> fmt::Debug for PossibleStrategy<S> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("PossibleStrategy")
+            .field("weights", &self.weights)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<S: Strategy> Strategy for PossibleStrategy<S> {
+    type Tree = PossibleValueTree<S::Tree>;
+    type Value = Possible<S::Value>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let state = self.weights.pick(any::<u64>().new_tree(runner)?.current());
+        let inner = match state {
+            Possible::Some(()) => Some(self.inner.new_tree(runner)?),
+            Possible::None | Possible::Void => None,
+        };
+
+        Ok(PossibleValueTree {
+            state,
+            simpler: simpler(self.weights, state),
+            previous: None,
+            inner,
+        })
+    }
+}
+
+/// Value tree of [`PossibleStrategy`], which shrinks toward `Possible::Void`.
+///
+/// A `Possible::Some` is first shrunk into `Possible::Void` and `Possible::None`, and only
+/// then is its inner value shrunk.
+#[derive(Clone, Debug)]
+pub struct PossibleValueTree<T> {
+    state: Possible<()>,
+    // simpler states left to try, from the simplest one
+    simpler: VecDeque<Possible<()>>,
+    // the state before the last simplification, until it is either kept or undone
+    previous: Option<Possible<()>>,
+    // only generated for `Possible::Some`
+    inner: Option<T>,
+}
+
+impl<T: ValueTree> ValueTree for PossibleValueTree<T> {
+    type Value = Possible<T::Value>;
+
+    fn current(&self) -> Self::Value {
+        match (self.state, &self.inner) {
+            (Possible::Some(()), Some(inner)) => Possible::Some(inner.current()),
+            (Possible::None, _) => Possible::None,
+            _ => Possible::Void,
+        }
+    }
+
+    fn simplify(&mut self) -> bool {
+        // the last simplification was kept, and nothing simpler is left to try
+        if self.previous.take().is_some() {
+            self.simpler.clear();
+            return false;
+        }
+
+        if let Some(state) = self.simpler.pop_front() {
+            self.previous = Some(self.state);
+            self.state = state;
+            return true;
+        }
+
+        match (self.state, &mut self.inner) {
+            (Possible::Some(()), Some(inner)) => inner.simplify(),
+            _ => false,
+        }
+    }
+
+    fn complicate(&mut self) -> bool {
+        if let Some(state) = self.previous.take() {
+            self.state = state;
+            return true;
+        }
+
+        match (self.state, &mut self.inner) {
+            (Possible::Some(()), Some(inner)) => inner.complicate(),
+            _ => false,
+        }
+    }
+}
+
+// the states simpler than `state` that can be generated, from the simplest one
+fn simpler(weights: Weights, state: Possible<()>) -> VecDeque<Possible<()>> {
+    let mut simpler = VecDeque::new();
+    if weights.void > 0 && !state.is_void() {
+        simpler.push_back(Possible::Void);
+    }
+    if weights.none > 0 && state.is_some() {
+        simpler.push_back(Possible::None);
+    }
+
+    simpler
+}
+
+impl<T: Arbitrary> Arbitrary for Possible<T> {
+    type Parameters = (Weights, T::Parameters);
+    type Strategy = PossibleStrategy<T::Strategy>;
+
+    fn arbitrary_with((weights, parameters): Self::Parameters) -> Self::Strategy {
+        strategy(weights, any_with::<T>(parameters))
+    }
+}
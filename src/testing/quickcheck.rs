@@ -0,0 +1,25 @@
+use super::weights::Weights;
+use crate::Possible;
+use quickcheck::{Arbitrary, Gen};
+use std::iter;
+
+// `Gen` takes no parameters per type, so the three states are weighed equally
+impl<T: Arbitrary> Arbitrary for Possible<T> {
+    fn arbitrary(generator: &mut Gen) -> Self {
+        Weights::default()
+            .pick(u64::arbitrary(generator))
+            .map(|()| T::arbitrary(generator))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self {
+            Possible::Some(value) => Box::new(
+                iter::once(Possible::Void)
+                    .chain(iter::once(Possible::None))
+                    .chain(value.shrink().map(Possible::Some)),
+            ),
+            Possible::None => Box::new(iter::once(Possible::Void)),
+            Possible::Void => Box::new(iter::empty()),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::Possible;
+
+/// The relative weights of the three states of a generated `Possible`.
+///
+/// A state with a weight of zero is never generated, nor shrunk into.
+///
+/// # Examples
+///
+/// ```
+/// use possible::Weights;
+///
+/// // one in ten values is `Possible::None`, and none are `Possible::Void`
+/// let weights = Weights::new(9, 1, 0);
+/// assert_eq!(weights.void, 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Weights {
+    /// The weight of `Possible::Some`.
+    pub some: u32,
+    /// The weight of `Possible::None`.
+    pub none: u32,
+    /// The weight of `Possible::Void`.
+    pub void: u32,
+}
+
+impl Weights {
+    /// Creates weights for `Possible::Some`, `Possible::None`, and `Possible::Void`.
+    pub const fn new(some: u32, none: u32, void: u32) -> Self {
+        Weights { some, none, void }
+    }
+
+    /// Picks the state at `sample` within the total of the weights, where `sample` is
+    /// uniformly random.
+    ///
+    /// Panics if every weight is zero.
+    pub(crate) fn pick(&self, sample: u64) -> Possible<()> {
+        let total = u64::from(self.some) + u64::from(self.none) + u64::from(self.void);
+        assert!(total > 0, "at least one `Possible` weight must not be zero");
+
+        let sample = sample % total;
+        if sample < u64::from(self.some) {
+            Possible::Some(())
+        } else if sample < u64::from(self.some) + u64::from(self.none) {
+            Possible::None
+        } else {
+            Possible::Void
+        }
+    }
+}
+
+impl Default for Weights {
+    /// Weighs the three states equally.
+    fn default() -> Self {
+        Weights::new(1, 1, 1)
+    }
+}
@@ -0,0 +1,48 @@
+//! Validation support for `Possible`, behind the `validator` feature.
+//!
+//! `Possible<T>` implements the [`validator`](https://docs.rs/validator) traits used by
+//! `#[derive(Validate)]` like an `Option<T>`: rules such as `length`, `range`, `email`,
+//! or `nested` only validate the value of a `Possible::Some`, so that a `Possible::None`
+//! or `Possible::Void` field always passes them. The `required` rule only accepts a
+//! `Possible::Some`.
+//!
+//! The state of a field is checked on its own with the rules of this module, which are
+//! used as `custom` rules:
+//!
+//! - [`not_null`] rejects `Possible::None`, for a field that may be absent but must not
+//!   be null.
+//! - [`not_void`] rejects `Possible::Void`, for a field that must be present but may be
+//!   null.
+//! - [`required`] rejects both, for a field that must be present and not null.
+//!
+//! The errors of these rules have the rule name as their code, and the
+//! [`FieldState`](crate::repr::FieldState) of the field (`"null"` or `"missing"`) as
+//! their `state` parameter.
+//!
+//! # Examples
+//!
+//! ```
+//! use possible::Possible;
+//! use validator::Validate;
+//!
+//! #[derive(Validate)]
+//! struct UserPatch {
+//!     #[validate(custom(function = "possible::validate::not_null"), length(min = 1, max = 50))]
+//!     name: Possible<String>,
+//! }
+//!
+//! let patch = UserPatch { name: Possible::Void };
+//! assert!(patch.validate().is_ok());
+//!
+//! let patch = UserPatch { name: Possible::None };
+//! let errors = patch.validate().unwrap_err();
+//! assert_eq!(errors.field_errors()["name"][0].code, "not_null");
+//!
+//! let patch = UserPatch { name: Possible::Some(String::new()) };
+//! let errors = patch.validate().unwrap_err();
+//! assert_eq!(errors.field_errors()["name"][0].code, "length");
+//! ```
+
+mod validator;
+
+pub use self::validator::{not_null, not_void, required};
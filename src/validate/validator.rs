@@ -0,0 +1,161 @@
+use crate::Possible;
+use std::borrow::Cow;
+use validator::{
+    AsRegex, Validate, ValidateArgs, ValidateContains, ValidateEmail, ValidateLength,
+    ValidateRange, ValidateRegex, ValidateRequired, ValidateUrl, ValidationError, ValidationErrors,
+};
+
+/// Rejects a `Possible::None` value, while allowing `Possible::Void`.
+///
+/// # Examples
+///
+/// ```
+/// use possible::{validate, Possible};
+///
+/// assert!(validate::not_null(&Possible::Some(42)).is_ok());
+/// assert!(validate::not_null::<i32>(&Possible::Void).is_ok());
+///
+/// let error = validate::not_null::<i32>(&Possible::None).unwrap_err();
+/// assert_eq!(error.code, "not_null");
+/// assert_eq!(error.params["state"], "null");
+/// ```
+pub fn not_null<T>(value: &Possible<T>) -> Result<(), ValidationError> {
+    match value {
+        Possible::None => Err(state_error("not_null", value)),
+        Possible::Some(_) | Possible::Void => Ok(()),
+    }
+}
+
+/// Rejects a `Possible::Void` value, while allowing `Possible::None`.
+///
+/// # Examples
+///
+/// ```
+/// use possible::{validate, Possible};
+///
+/// assert!(validate::not_void(&Possible::Some(42)).is_ok());
+/// assert!(validate::not_void::<i32>(&Possible::None).is_ok());
+///
+/// let error = validate::not_void::<i32>(&Possible::Void).unwrap_err();
+/// assert_eq!(error.code, "not_void");
+/// assert_eq!(error.params["state"], "missing");
+/// ```
+pub fn not_void<T>(value: &Possible<T>) -> Result<(), ValidationError> {
+    match value {
+        Possible::Void => Err(state_error("not_void", value)),
+        Possible::Some(_) | Possible::None => Ok(()),
+    }
+}
+
+/// Rejects both `Possible::None` and `Possible::Void` values.
+///
+/// # Examples
+///
+/// ```
+/// use possible::{validate, Possible};
+///
+/// assert!(validate::required(&Possible::Some(42)).is_ok());
+///
+/// let error = validate::required::<i32>(&Possible::None).unwrap_err();
+/// assert_eq!(error.code, "required");
+/// assert_eq!(error.params["state"], "null");
+/// ```
+pub fn required<T>(value: &Possible<T>) -> Result<(), ValidationError> {
+    match value {
+        Possible::Some(_) => Ok(()),
+        Possible::None | Possible::Void => Err(state_error("required", value)),
+    }
+}
+
+fn state_error<T>(code: &'static str, value: &Possible<T>) -> ValidationError {
+    let mut error = ValidationError::new(code);
+    error.add_param(Cow::Borrowed("state"), &value.state().to_string());
+    error
+}
+
+impl<T: Validate> Validate for Possible<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            Possible::Some(value) => value.validate(),
+            Possible::None | Possible::Void => Ok(()),
+        }
+    }
+}
+
+impl<'v_a, T: ValidateArgs<'v_a>> ValidateArgs<'v_a> for Possible<T> {
+    type Args = T::Args;
+
+    fn validate_with_args(&self, args: Self::Args) -> Result<(), ValidationErrors> {
+        match self {
+            Possible::Some(value) => value.validate_with_args(args),
+            Possible::None | Possible::Void => Ok(()),
+        }
+    }
+}
+
+impl<T: ValidateLength<u64>> ValidateLength<u64> for Possible<T> {
+    fn length(&self) -> Option<u64> {
+        match self {
+            Possible::Some(value) => value.length(),
+            Possible::None | Possible::Void => None,
+        }
+    }
+}
+
+impl<T, U: ValidateRange<T>> ValidateRange<T> for Possible<U> {
+    fn greater_than(&self, max: T) -> Option<bool> {
+        match self {
+            Possible::Some(value) => value.greater_than(max),
+            Possible::None | Possible::Void => None,
+        }
+    }
+
+    fn less_than(&self, min: T) -> Option<bool> {
+        match self {
+            Possible::Some(value) => value.less_than(min),
+            Possible::None | Possible::Void => None,
+        }
+    }
+}
+
+impl<T: ValidateContains> ValidateContains for Possible<T> {
+    fn validate_contains(&self, needle: &str) -> bool {
+        match self {
+            Possible::Some(value) => value.validate_contains(needle),
+            Possible::None | Possible::Void => true,
+        }
+    }
+}
+
+impl<T: ValidateRegex> ValidateRegex for Possible<T> {
+    fn validate_regex(&self, regex: impl AsRegex) -> bool {
+        match self {
+            Possible::Some(value) => value.validate_regex(regex),
+            Possible::None | Possible::Void => true,
+        }
+    }
+}
+
+impl<T: ValidateEmail> ValidateEmail for Possible<T> {
+    fn as_email_string(&self) -> Option<Cow<'_, str>> {
+        match self {
+            Possible::Some(value) => value.as_email_string(),
+            Possible::None | Possible::Void => None,
+        }
+    }
+}
+
+impl<T: ValidateUrl> ValidateUrl for Possible<T> {
+    fn as_url_string(&self) -> Option<Cow<'_, str>> {
+        match self {
+            Possible::Some(value) => value.as_url_string(),
+            Possible::None | Possible::Void => None,
+        }
+    }
+}
+
+impl<T> ValidateRequired for Possible<T> {
+    fn is_some(&self) -> bool {
+        Possible::is_some(self)
+    }
+}
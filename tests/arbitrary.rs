@@ -0,0 +1,68 @@
+//! Tests for generating `Possible` values from unstructured fuzzer input.
+
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use possible::Possible;
+use pretty_assertions::assert_eq;
+
+fn from_bytes(bytes: &[u8]) -> Possible<u8> {
+    Possible::arbitrary(&mut Unstructured::new(bytes)).unwrap()
+}
+
+#[test]
+fn with_some_value() {
+    assert_eq!(
+        from_bytes(&[2, 42]),
+        Possible::Some(42),
+        "Failed to read some value"
+    );
+}
+
+#[test]
+fn with_null_value() {
+    assert_eq!(
+        from_bytes(&[1, 42]),
+        Possible::None,
+        "Failed to read null value"
+    );
+}
+
+#[test]
+fn with_void_value() {
+    assert_eq!(
+        from_bytes(&[0, 42]),
+        Possible::Void,
+        "Failed to read void value"
+    );
+}
+
+#[test]
+fn with_exhausted_input() {
+    assert_eq!(
+        from_bytes(&[]),
+        Possible::Void,
+        "Failed to read void from no input"
+    );
+}
+
+#[test]
+fn with_every_state() {
+    let values: Vec<_> = (0..=u8::MAX).map(|byte| from_bytes(&[byte, 0])).collect();
+
+    assert!(
+        values.iter().any(Possible::is_some)
+            && values.iter().any(Possible::is_none)
+            && values.iter().any(Possible::is_void),
+        "Failed to read every state"
+    );
+}
+
+#[test]
+fn with_size_hint() {
+    assert_eq!(
+        Possible::<u32>::size_hint(0),
+        (1, Some(5)),
+        "Failed to hint size of state and value"
+    );
+}
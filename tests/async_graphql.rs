@@ -0,0 +1,207 @@
+//! Tests for `Possible` arguments, input fields, and results with an in-process
+//! async-graphql schema.
+
+#![cfg(feature = "async-graphql")]
+
+use async_graphql::{EmptySubscription, InputObject, MaybeUndefined, Object, Schema, SimpleObject};
+use possible::Possible;
+use std::sync::Mutex;
+
+#[derive(Clone, Debug, PartialEq, SimpleObject)]
+pub struct User {
+    name: String,
+    email: Option<String>,
+    age: Possible<i32>,
+}
+
+#[derive(InputObject)]
+pub struct UserInput {
+    name: Possible<String>,
+    email: Possible<String>,
+}
+
+pub struct Query;
+
+#[Object]
+impl Query {
+    async fn user(&self, ctx: &async_graphql::Context<'_>) -> User {
+        ctx.data_unchecked::<Mutex<User>>().lock().unwrap().clone()
+    }
+
+    async fn state(&self, value: Possible<i32>) -> String {
+        match value {
+            Possible::Some(value) => format!("some {}", value),
+            Possible::None => String::from("none"),
+            Possible::Void => String::from("void"),
+        }
+    }
+
+    async fn echo(&self, value: Possible<i32>) -> Possible<i32> {
+        value
+    }
+}
+
+pub struct Mutation;
+
+#[Object]
+impl Mutation {
+    async fn update_user(&self, ctx: &async_graphql::Context<'_>, input: UserInput) -> User {
+        let mut user = ctx.data_unchecked::<Mutex<User>>().lock().unwrap();
+
+        match input.name {
+            Possible::Some(name) => user.name = name,
+            Possible::None | Possible::Void => {}
+        }
+        match input.email {
+            Possible::Some(email) => user.email = Some(email),
+            Possible::None => user.email = None,
+            Possible::Void => {}
+        }
+
+        user.clone()
+    }
+}
+
+fn execute(query: &str) -> async_graphql::Value {
+    let schema = Schema::build(Query, Mutation, EmptySubscription)
+        .data(Mutex::new(User {
+            name: String::from("Ferris"),
+            email: Some(String::from("ferris@example.com")),
+            age: Possible::Some(7),
+        }))
+        .finish();
+
+    let response = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+        .block_on(schema.execute(query));
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    response.data
+}
+
+mod input {
+    use super::execute;
+    use async_graphql::value;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_all_states() {
+        let data = execute(
+            r#"{
+                some: state(value: 7)
+                none: state(value: null)
+                void: state
+            }"#,
+        );
+
+        assert_eq!(
+            data,
+            value!({ "some": "some 7", "none": "none", "void": "void" }),
+            "Failed to parse expected argument states"
+        );
+    }
+
+    #[test]
+    fn with_null_mutation() {
+        let data = execute(r#"mutation { updateUser(input: { email: null }) { name email } }"#);
+
+        assert_eq!(
+            data,
+            value!({ "updateUser": { "name": "Ferris", "email": null } }),
+            "Failed to clear null input field"
+        );
+    }
+
+    #[test]
+    fn with_some_mutation() {
+        let data = execute(
+            r#"mutation { updateUser(input: { name: "Corro", email: "corro@example.com" }) { name email } }"#,
+        );
+
+        assert_eq!(
+            data,
+            value!({ "updateUser": { "name": "Corro", "email": "corro@example.com" } }),
+            "Failed to set present input fields"
+        );
+    }
+
+    #[test]
+    fn with_void_mutation() {
+        let data = execute(r#"mutation { updateUser(input: {}) { name email } }"#);
+
+        assert_eq!(
+            data,
+            value!({ "updateUser": { "name": "Ferris", "email": "ferris@example.com" } }),
+            "Failed to leave missing input fields unchanged"
+        );
+    }
+}
+
+mod output {
+    use super::execute;
+    use async_graphql::value;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_all_states() {
+        let data = execute(
+            r#"{
+                user { age }
+                some: echo(value: 7)
+                none: echo(value: null)
+                void: echo
+            }"#,
+        );
+
+        assert_eq!(
+            data,
+            value!({ "user": { "age": 7 }, "some": 7, "none": null, "void": null }),
+            "Failed to resolve expected values"
+        );
+    }
+
+    #[test]
+    fn with_nullable_type() {
+        let data = execute(r#"{ __type(name: "User") { fields { name type { kind } } } }"#);
+
+        assert_eq!(
+            data,
+            value!({ "__type": { "fields": [
+                { "name": "name", "type": { "kind": "NON_NULL" } },
+                { "name": "email", "type": { "kind": "SCALAR" } },
+                { "name": "age", "type": { "kind": "SCALAR" } },
+            ] } }),
+            "Failed to declare possible field as nullable"
+        );
+    }
+}
+
+mod conversion {
+    use super::{MaybeUndefined, Possible};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_all_states() {
+        let values = [
+            MaybeUndefined::Value(7),
+            MaybeUndefined::Null,
+            MaybeUndefined::Undefined,
+        ];
+        let possible: Vec<Possible<i32>> = values.iter().cloned().map(Possible::from).collect();
+
+        assert_eq!(
+            possible,
+            [Possible::Some(7), Possible::None, Possible::Void],
+            "Failed to convert from maybe undefined values"
+        );
+
+        let round_trip: Vec<MaybeUndefined<i32>> =
+            possible.into_iter().map(MaybeUndefined::from).collect();
+
+        assert_eq!(
+            round_trip, values,
+            "Failed to convert back to maybe undefined values"
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! Tests for parsing `Possible` options from the command line with `clap`.
+
+#![cfg(feature = "clap")]
+
+use clap::{builder::StringValueParser, error::ErrorKind, value_parser, ArgMatches, Command};
+use possible::Possible;
+
+fn command() -> Command {
+    let command = Command::new("update");
+    let command = possible::cli::augment_args(command, "description", StringValueParser::new());
+    possible::cli::augment_args(command, "limit", value_parser!(u32))
+}
+
+fn matches(argv: &[&str]) -> ArgMatches {
+    command()
+        .try_get_matches_from(argv)
+        .expect("Failed to parse arguments")
+}
+
+mod builder {
+    use super::{command, matches, ErrorKind, Possible};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_value() {
+        let matches = matches(&["update", "--description", "Crab", "--limit=10"]);
+
+        assert_eq!(
+            possible::cli::from_arg_matches(&matches, "description"),
+            Possible::Some(String::from("Crab")),
+            "Failed to parse present description"
+        );
+        assert_eq!(
+            possible::cli::from_arg_matches(&matches, "limit"),
+            Possible::Some(10u32),
+            "Failed to parse present limit"
+        );
+    }
+
+    #[test]
+    fn with_empty_value() {
+        let matches = matches(&["update", "--description=", "--limit", ""]);
+
+        assert_eq!(
+            possible::cli::from_arg_matches::<String>(&matches, "description"),
+            Possible::None,
+            "Failed to parse empty description"
+        );
+        assert_eq!(
+            possible::cli::from_arg_matches::<u32>(&matches, "limit"),
+            Possible::None,
+            "Failed to parse empty limit"
+        );
+    }
+
+    #[test]
+    fn with_clear_flag() {
+        let matches = matches(&["update", "--no-description", "--no-limit"]);
+
+        assert_eq!(
+            possible::cli::from_arg_matches::<String>(&matches, "description"),
+            Possible::None,
+            "Failed to parse cleared description"
+        );
+        assert_eq!(
+            possible::cli::from_arg_matches::<u32>(&matches, "limit"),
+            Possible::None,
+            "Failed to parse cleared limit"
+        );
+    }
+
+    #[test]
+    fn with_omitted_value() {
+        let matches = matches(&["update"]);
+
+        assert_eq!(
+            possible::cli::from_arg_matches::<String>(&matches, "description"),
+            Possible::Void,
+            "Failed to parse omitted description"
+        );
+        assert_eq!(
+            possible::cli::from_arg_matches::<u32>(&matches, "limit"),
+            Possible::Void,
+            "Failed to parse omitted limit"
+        );
+    }
+
+    #[test]
+    fn with_invalid_arguments() {
+        let error = command()
+            .try_get_matches_from(["update", "--description", "Crab", "--no-description"])
+            .unwrap_err();
+        assert_eq!(
+            error.kind(),
+            ErrorKind::ArgumentConflict,
+            "Failed to reject conflicting flags"
+        );
+
+        let error = command()
+            .try_get_matches_from(["update", "--limit", "ten"])
+            .unwrap_err();
+        assert_eq!(
+            error.kind(),
+            ErrorKind::ValueValidation,
+            "Failed to reject invalid value"
+        );
+    }
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use super::{ErrorKind, Possible};
+    use clap::Parser;
+    use possible::{
+        cli::{ArgName, ClearableArg},
+        Patch,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Default, PartialEq, Patch)]
+    struct Project {
+        description: Option<String>,
+        limit: Option<u32>,
+    }
+
+    #[derive(Debug)]
+    struct Description;
+
+    impl ArgName for Description {
+        const NAME: &'static str = "description";
+    }
+
+    #[derive(Debug)]
+    struct Limit;
+
+    impl ArgName for Limit {
+        const NAME: &'static str = "limit";
+    }
+
+    #[derive(Debug, Parser)]
+    #[command(name = "update")]
+    struct Update {
+        #[command(flatten)]
+        description: ClearableArg<String, Description>,
+
+        #[command(flatten)]
+        limit: ClearableArg<u32, Limit>,
+    }
+
+    impl From<Update> for ProjectPatch {
+        fn from(args: Update) -> Self {
+            ProjectPatch {
+                description: args.description.into(),
+                limit: args.limit.into(),
+            }
+        }
+    }
+
+    fn patched(argv: &[&str]) -> Project {
+        let args = Update::try_parse_from(argv).expect("Failed to parse arguments");
+        let mut project = Project {
+            description: Some(String::from("Ferris")),
+            limit: Some(5),
+        };
+        ProjectPatch::from(args)
+            .apply(&mut project)
+            .expect("Failed to apply patch");
+        project
+    }
+
+    #[test]
+    fn with_patch() {
+        assert_eq!(
+            patched(&["update", "--description", "Crab", "--no-limit"]),
+            Project {
+                description: Some(String::from("Crab")),
+                limit: None,
+            },
+            "Failed to set and clear fields"
+        );
+        assert_eq!(
+            patched(&["update", "--description="]),
+            Project {
+                description: None,
+                limit: Some(5),
+            },
+            "Failed to clear one field"
+        );
+        assert_eq!(
+            patched(&["update"]),
+            Project {
+                description: Some(String::from("Ferris")),
+                limit: Some(5),
+            },
+            "Failed to leave fields unchanged"
+        );
+    }
+
+    #[test]
+    fn with_update() {
+        let mut args = Update::try_parse_from(["update", "--description", "Crab", "--limit=10"])
+            .expect("Failed to parse arguments");
+        args.try_update_from(["update", "--no-description"])
+            .expect("Failed to update arguments");
+
+        assert_eq!(
+            (args.description.into_inner(), args.limit.into_inner()),
+            (Possible::None, Possible::Some(10)),
+            "Failed to keep the arguments left out of the update"
+        );
+    }
+
+    #[test]
+    fn with_invalid_arguments() {
+        let error = Update::try_parse_from(["update", "--limit=1", "--no-limit"]).unwrap_err();
+
+        assert_eq!(
+            error.kind(),
+            ErrorKind::ArgumentConflict,
+            "Failed to reject conflicting flags"
+        );
+
+        let error = Update::try_parse_from(["update", "--limit", "ten"]).unwrap_err();
+
+        assert_eq!(
+            error.kind(),
+            ErrorKind::ValueValidation,
+            "Failed to reject invalid value"
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! Tests for collecting, summing, and multiplying iterators of `Possible` values.
+
+use possible::{Possible, Precedence};
+
+mod from_iter {
+    use super::{Possible, Precedence};
+    use pretty_assertions::assert_eq;
+
+    fn collect(values: &[Possible<u8>], precedence: Precedence) -> Possible<Vec<u8>> {
+        Possible::from_iter_with(values.iter().copied(), precedence)
+    }
+
+    #[test]
+    fn with_some_values() {
+        let values = [Possible::Some(1), Possible::Some(2)];
+
+        for precedence in [
+            Precedence::FirstWins,
+            Precedence::VoidDominates,
+            Precedence::NoneDominates,
+        ] {
+            assert_eq!(
+                collect(&values, precedence),
+                Possible::Some(vec![1, 2]),
+                "Failed to collect some values with {:?}",
+                precedence
+            );
+        }
+    }
+
+    #[test]
+    fn with_no_values() {
+        assert_eq!(
+            collect(&[], Precedence::FirstWins),
+            Possible::Some(vec![]),
+            "Failed to collect empty iterator"
+        );
+    }
+
+    #[test]
+    fn with_void_value() {
+        let values = [Possible::Some(1), Possible::Void, Possible::Some(2)];
+
+        assert_eq!(
+            values.iter().copied().collect::<Possible<Vec<u8>>>(),
+            Possible::Void,
+            "Failed to keep void value"
+        );
+    }
+
+    #[test]
+    fn with_first_wins() {
+        assert_eq!(
+            collect(
+                &[Possible::Void, Possible::None, Possible::Some(1)],
+                Precedence::FirstWins
+            ),
+            Possible::Void,
+            "Failed to pick first void value"
+        );
+        assert_eq!(
+            collect(
+                &[Possible::Some(1), Possible::None, Possible::Void],
+                Precedence::FirstWins
+            ),
+            Possible::None,
+            "Failed to pick first null value"
+        );
+    }
+
+    #[test]
+    fn with_first_wins_unread() {
+        let mut values = vec![Possible::Some(1), Possible::None, Possible::Some(2)].into_iter();
+        let collected: Possible<Vec<u8>> = values.by_ref().collect();
+
+        assert_eq!(collected, Possible::None, "Failed to stop at null value");
+        assert_eq!(
+            values.next(),
+            Some(Possible::Some(2)),
+            "Failed to leave rest of iterator unread"
+        );
+    }
+
+    #[test]
+    fn with_void_dominates() {
+        assert_eq!(
+            collect(
+                &[Possible::None, Possible::Some(1), Possible::Void],
+                Precedence::VoidDominates
+            ),
+            Possible::Void,
+            "Failed to pick later void value"
+        );
+        assert_eq!(
+            collect(
+                &[Possible::Some(1), Possible::None, Possible::None],
+                Precedence::VoidDominates
+            ),
+            Possible::None,
+            "Failed to pick null value without void value"
+        );
+    }
+
+    #[test]
+    fn with_none_dominates() {
+        assert_eq!(
+            collect(
+                &[Possible::Void, Possible::Some(1), Possible::None],
+                Precedence::NoneDominates
+            ),
+            Possible::None,
+            "Failed to pick later null value"
+        );
+        assert_eq!(
+            collect(
+                &[Possible::Some(1), Possible::Void, Possible::Void],
+                Precedence::NoneDominates
+            ),
+            Possible::Void,
+            "Failed to pick void value without null value"
+        );
+    }
+}
+
+mod arithmetic {
+    use super::Possible;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_sum() {
+        let values = [Possible::Some(1u32), Possible::Some(2), Possible::Some(3)];
+
+        assert_eq!(
+            values.iter().copied().sum::<Possible<u32>>(),
+            Possible::Some(6),
+            "Failed to sum some values"
+        );
+    }
+
+    #[test]
+    fn with_sum_of_void_value() {
+        let values = [Possible::Some(1u32), Possible::Void, Possible::None];
+
+        assert_eq!(
+            values.iter().copied().sum::<Possible<u32>>(),
+            Possible::Void,
+            "Failed to keep void value in sum"
+        );
+    }
+
+    #[test]
+    fn with_product() {
+        let values = [Possible::Some(2u32), Possible::Some(3), Possible::Some(4)];
+
+        assert_eq!(
+            values.iter().copied().product::<Possible<u32>>(),
+            Possible::Some(24),
+            "Failed to multiply some values"
+        );
+    }
+
+    #[test]
+    fn with_product_of_null_value() {
+        let values = [Possible::Some(2u32), Possible::None, Possible::Void];
+
+        assert_eq!(
+            values.iter().copied().product::<Possible<u32>>(),
+            Possible::None,
+            "Failed to keep null value in product"
+        );
+    }
+}
@@ -0,0 +1,304 @@
+//! Tests for writing and reading `Possible` values with diesel and an in-memory SQLite
+//! database.
+
+#![cfg(feature = "diesel")]
+
+use diesel::{prelude::*, sqlite::SqliteConnection};
+use possible::Possible;
+
+diesel::table! {
+    users {
+        id -> Integer,
+        name -> Text,
+        email -> Nullable<Text>,
+        age -> Nullable<Integer>,
+    }
+}
+
+#[derive(Debug, Queryable, PartialEq)]
+pub struct User {
+    id: i32,
+    name: String,
+    email: Possible<String>,
+    age: Possible<i32>,
+}
+
+#[derive(possible::sql::Insertable)]
+#[diesel(table_name = users)]
+pub struct NewUser {
+    name: Option<String>,
+    email: Possible<String>,
+    age: Possible<i32>,
+}
+
+#[derive(possible::sql::AsChangeset)]
+#[diesel(table_name = users)]
+pub struct UserChanges {
+    // left out of the changeset as the primary key
+    #[allow(dead_code)]
+    id: i32,
+    name: Option<String>,
+    #[diesel(column_name = email)]
+    email_address: Possible<String>,
+    age: Possible<i32>,
+}
+
+fn connect() -> SqliteConnection {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+
+    diesel::sql_query(
+        "CREATE TABLE users (
+            id INTEGER PRIMARY KEY,
+            name TEXT NOT NULL DEFAULT 'anonymous',
+            email TEXT,
+            age INTEGER DEFAULT 7
+        )",
+    )
+    .execute(&mut connection)
+    .unwrap();
+
+    connection
+}
+
+fn insert(connection: &mut SqliteConnection, user: NewUser) -> User {
+    diesel::insert_into(users::table)
+        .values(user)
+        .execute(connection)
+        .unwrap();
+
+    users::table
+        .order(users::id.desc())
+        .first(connection)
+        .unwrap()
+}
+
+fn ferris() -> NewUser {
+    NewUser {
+        name: Some(String::from("Ferris")),
+        email: Possible::Some(String::from("ferris@example.com")),
+        age: Possible::Some(8),
+    }
+}
+
+mod encoding {
+    use super::{connect, Possible};
+    use diesel::{
+        select,
+        sql_types::{Nullable, Text},
+        IntoSql, RunQueryDsl,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_some_value() {
+        let mut connection = connect();
+        let value: Option<String> = select(Possible::Some("Ferris").into_sql::<Nullable<Text>>())
+            .get_result(&mut connection)
+            .unwrap();
+
+        assert_eq!(
+            value,
+            Some(String::from("Ferris")),
+            "Failed to bind expected value"
+        );
+    }
+
+    #[test]
+    fn with_null_value() {
+        let mut connection = connect();
+        let value: Option<String> = select(Possible::<&str>::None.into_sql::<Nullable<Text>>())
+            .get_result(&mut connection)
+            .unwrap();
+
+        assert_eq!(value, None, "Failed to bind null value");
+    }
+
+    #[test]
+    fn with_void_value() {
+        let mut connection = connect();
+        let result = select(Possible::<&str>::Void.into_sql::<Nullable<Text>>())
+            .get_result::<Option<String>>(&mut connection);
+
+        assert!(result.is_err(), "Failed to reject void value");
+    }
+}
+
+mod decoding {
+    use super::{connect, Possible};
+    use diesel::{
+        dsl::sql,
+        select,
+        sql_types::{Integer, Nullable},
+        RunQueryDsl,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_some_value() {
+        let mut connection = connect();
+        let value: Possible<i32> = select(sql::<Nullable<Integer>>("42"))
+            .get_result(&mut connection)
+            .unwrap();
+
+        assert_eq!(value, Possible::Some(42), "Failed to read expected value");
+    }
+
+    #[test]
+    fn with_null_value() {
+        let mut connection = connect();
+        let value: Possible<i32> = select(sql::<Nullable<Integer>>("NULL"))
+            .get_result(&mut connection)
+            .unwrap();
+
+        assert_eq!(value, Possible::None, "Failed to read null value");
+    }
+}
+
+mod insertable {
+    use super::{connect, insert, NewUser, Possible, User};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_all_states() {
+        let mut connection = connect();
+        let user = insert(
+            &mut connection,
+            NewUser {
+                name: Some(String::from("Ferris")),
+                email: Possible::None,
+                age: Possible::Void,
+            },
+        );
+
+        assert_eq!(
+            user,
+            User {
+                id: 1,
+                name: String::from("Ferris"),
+                email: Possible::None,
+                age: Possible::Some(7),
+            },
+            "Failed to apply default for void column"
+        );
+    }
+
+    #[test]
+    fn with_only_defaults() {
+        let mut connection = connect();
+        let user = insert(
+            &mut connection,
+            NewUser {
+                name: None,
+                email: Possible::Void,
+                age: Possible::Void,
+            },
+        );
+
+        assert_eq!(
+            user,
+            User {
+                id: 1,
+                name: String::from("anonymous"),
+                email: Possible::None,
+                age: Possible::Some(7),
+            },
+            "Failed to apply defaults for all columns"
+        );
+    }
+}
+
+mod changeset {
+    use super::{connect, ferris, insert, users, Possible, User, UserChanges};
+    use diesel::{debug_query, prelude::*, sqlite::Sqlite};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_all_states() {
+        let mut connection = connect();
+        insert(&mut connection, ferris());
+
+        let changes = UserChanges {
+            id: 1,
+            name: None,
+            email_address: Possible::None,
+            age: Possible::Some(9),
+        };
+        let query = diesel::update(users::table.find(1)).set(changes);
+
+        assert_eq!(
+            debug_query::<Sqlite, _>(&query).to_string(),
+            "UPDATE `users` SET `email` = ?, `age` = ? WHERE (`users`.`id` = ?) \
+             -- binds: [None, Some(9), 1]",
+            "Failed to leave out primary key and unset columns"
+        );
+
+        query.execute(&mut connection).unwrap();
+        let user: User = users::table.find(1).first(&mut connection).unwrap();
+
+        assert_eq!(
+            user,
+            User {
+                id: 1,
+                name: String::from("Ferris"),
+                email: Possible::None,
+                age: Possible::Some(9),
+            },
+            "Failed to update only present columns"
+        );
+    }
+
+    #[test]
+    fn with_void_columns() {
+        let mut connection = connect();
+        insert(&mut connection, ferris());
+
+        let changes = UserChanges {
+            id: 1,
+            name: Some(String::from("Corro")),
+            email_address: Possible::Void,
+            age: Possible::Void,
+        };
+        diesel::update(users::table.find(1))
+            .set(changes)
+            .execute(&mut connection)
+            .unwrap();
+        let user: User = users::table.find(1).first(&mut connection).unwrap();
+
+        assert_eq!(
+            user,
+            User {
+                id: 1,
+                name: String::from("Corro"),
+                email: Possible::Some(String::from("ferris@example.com")),
+                age: Possible::Some(8),
+            },
+            "Failed to leave void columns unchanged"
+        );
+    }
+
+    #[test]
+    fn with_assign() {
+        let mut connection = connect();
+        insert(&mut connection, ferris());
+
+        diesel::update(users::table.find(1))
+            .set((
+                possible::sql::assign(users::email, Possible::<String>::Void),
+                possible::sql::assign(users::age, Possible::<i32>::None),
+            ))
+            .execute(&mut connection)
+            .unwrap();
+        let user: User = users::table.find(1).first(&mut connection).unwrap();
+
+        assert_eq!(
+            user,
+            User {
+                id: 1,
+                name: String::from("Ferris"),
+                email: Possible::Some(String::from("ferris@example.com")),
+                age: Possible::None,
+            },
+            "Failed to assign only present columns"
+        );
+    }
+}
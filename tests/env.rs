@@ -0,0 +1,184 @@
+//! Tests for reading `Possible` values from environment variables.
+
+#![cfg(feature = "std")]
+
+use possible::{env::EnvError, Possible};
+use std::collections::HashMap;
+
+fn env() -> HashMap<&'static str, &'static str> {
+    HashMap::from([
+        ("APP_PORT", "8080"),
+        ("APP_PROXY", ""),
+        ("APP_WORKERS", "many"),
+        ("APP_HOST", "localhost"),
+    ])
+}
+
+mod from_env {
+    use super::{env, EnvError, HashMap, Possible};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_value() {
+        assert_eq!(
+            Possible::from_env_source(&env(), "APP_PORT"),
+            Ok(Possible::Some(8080u16)),
+            "Failed to parse set variable"
+        );
+    }
+
+    #[test]
+    fn with_empty_value() {
+        assert_eq!(
+            Possible::<u16>::from_env_source(&env(), "APP_PROXY"),
+            Ok(Possible::None),
+            "Failed to parse empty variable"
+        );
+    }
+
+    #[test]
+    fn with_unset_value() {
+        assert_eq!(
+            Possible::<u16>::from_env_source(&env(), "APP_TIMEOUT"),
+            Ok(Possible::Void),
+            "Failed to parse unset variable"
+        );
+    }
+
+    #[test]
+    fn with_invalid_value() {
+        assert_eq!(
+            Possible::<u8>::from_env_source(&env(), "APP_WORKERS"),
+            Err(EnvError::Parse(
+                String::from("APP_WORKERS"),
+                String::from("invalid digit found in string")
+            )),
+            "Failed to reject invalid variable"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn with_non_unicode_value() {
+        use std::{ffi::OsString, os::unix::ffi::OsStringExt};
+
+        let env = HashMap::from([("APP_HOST", OsString::from_vec(vec![0x66, 0x80]))]);
+
+        assert_eq!(
+            Possible::<String>::from_env_source(&env, "APP_HOST"),
+            Err(EnvError::NotUnicode(String::from("APP_HOST"))),
+            "Failed to reject non unicode variable"
+        );
+    }
+
+    #[test]
+    fn with_process_env() {
+        std::env::set_var("POSSIBLE_TEST_FROM_ENV_PORT", "");
+
+        assert_eq!(
+            Possible::<u16>::from_env("POSSIBLE_TEST_FROM_ENV_PORT"),
+            Ok(Possible::None),
+            "Failed to read process variable"
+        );
+    }
+}
+
+#[cfg(feature = "derive")]
+mod with_derive {
+    use super::{env, EnvError, Possible};
+    use possible::env::FromEnv;
+    use pretty_assertions::assert_eq;
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq, FromEnv)]
+    #[env(prefix = "APP_")]
+    struct Config {
+        port: Possible<u16>,
+        proxy: Possible<String>,
+        #[env(rename = "HOST")]
+        hostname: Possible<String>,
+        r#type: Possible<String>,
+    }
+
+    #[derive(Debug, PartialEq, FromEnv)]
+    #[env(prefix = "APP_")]
+    struct Workers {
+        port: Possible<u16>,
+        workers: Possible<u8>,
+        proxy: Possible<u16>,
+        timeout: Possible<u32>,
+    }
+
+    #[derive(Debug, PartialEq, FromEnv)]
+    #[env(prefix = "LOG_")]
+    struct Logging {
+        source: Possible<String>,
+        errors: Possible<u32>,
+    }
+
+    #[test]
+    fn with_valid_values() {
+        assert_eq!(
+            Config::from_env_source(&env()),
+            Ok(Config {
+                port: Possible::Some(8080),
+                proxy: Possible::None,
+                hostname: Possible::Some(String::from("localhost")),
+                r#type: Possible::Void,
+            }),
+            "Failed to read struct from variables"
+        );
+    }
+
+    #[test]
+    fn with_local_field_names() {
+        let env = BTreeMap::from([("LOG_SOURCE", "stderr"), ("LOG_ERRORS", "3")]);
+
+        assert_eq!(
+            Logging::from_env_source(&env),
+            Ok(Logging {
+                source: Possible::Some(String::from("stderr")),
+                errors: Possible::Some(3),
+            }),
+            "Failed to read fields named like the locals of the derive"
+        );
+    }
+
+    #[test]
+    fn with_invalid_values() {
+        let env = BTreeMap::from([
+            (String::from("APP_PORT"), String::from("http")),
+            (String::from("APP_WORKERS"), String::from("-1")),
+            (String::from("APP_PROXY"), String::new()),
+        ]);
+        let errors = Workers::from_env_source(&env).unwrap_err();
+
+        assert_eq!(
+            errors.into_iter().collect::<Vec<_>>(),
+            [
+                EnvError::Parse(
+                    String::from("APP_PORT"),
+                    String::from("invalid digit found in string")
+                ),
+                EnvError::Parse(
+                    String::from("APP_WORKERS"),
+                    String::from("invalid digit found in string")
+                ),
+            ],
+            "Failed to collect the errors of every field"
+        );
+    }
+
+    #[test]
+    fn with_error_message() {
+        let env = BTreeMap::from([("APP_PORT", "http"), ("APP_WORKERS", "300")]);
+        let errors = Workers::from_env_source(&env).unwrap_err();
+
+        assert_eq!(
+            errors.to_string(),
+            "environment variable `APP_PORT` is invalid: invalid digit found in string; \
+             environment variable `APP_WORKERS` is invalid: number too large to fit in target type",
+            "Failed to format errors"
+        );
+    }
+}
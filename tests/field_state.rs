@@ -0,0 +1,151 @@
+//! Tests for reporting the state of a field that failed to deserialize or validate.
+
+#![cfg(feature = "serde")]
+
+mod with_possible {
+    use possible::{repr::FieldState, Possible};
+    use serde::Deserialize;
+
+    #[derive(Debug, Default, Deserialize, PartialEq)]
+    pub struct ParseTest {
+        #[serde(default, deserialize_with = "Possible::deserialize_field")]
+        test: Possible<i64>,
+    }
+
+    mod deserialization {
+        use super::{FieldState, ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+        use serde::{
+            de::value::{I128Deserializer, I64Deserializer, StrDeserializer},
+            Deserialize,
+        };
+
+        #[test]
+        fn with_some_value() {
+            let parsed: ParseTest = serde_json::from_str(r#"{ "test": 123 }"#).unwrap();
+
+            assert_eq!(
+                parsed.test.state(),
+                FieldState::Present,
+                "Failed to report present value"
+            );
+        }
+
+        #[test]
+        fn with_null_value() {
+            let parsed: ParseTest = serde_json::from_str(r#"{ "test": null }"#).unwrap();
+
+            assert_eq!(
+                parsed.test.state(),
+                FieldState::Null,
+                "Failed to report null value"
+            );
+        }
+
+        #[test]
+        fn with_no_field() {
+            let parsed: ParseTest = serde_json::from_str("{}").unwrap();
+
+            assert_eq!(
+                parsed.test.state(),
+                FieldState::Missing,
+                "Failed to report missing field"
+            );
+        }
+
+        #[test]
+        fn with_invalid_value() {
+            let error = serde_json::from_str::<ParseTest>(r#"{ "test": "123" }"#).unwrap_err();
+
+            assert_eq!(
+                error.to_string(),
+                r#"present field: invalid type: string "123", expected i64 at line 1 column 15"#,
+                "Failed to report state of invalid value"
+            );
+        }
+
+        #[test]
+        fn with_unexpected_input() {
+            let deserializer = StrDeserializer::<serde::de::value::Error>::new("123");
+            let error = Possible::<i64>::deserialize(deserializer).unwrap_err();
+
+            assert_eq!(
+                error.to_string(),
+                r#"invalid type: string "123", expected a value of type `i64`, null, or unit"#,
+                "Failed to name expected inner type"
+            );
+
+            let deserializer = I128Deserializer::<serde::de::value::Error>::new(123);
+            let error = possible::repr::as_empty_none::deserialize::<i64, _>(deserializer)
+                .unwrap_err();
+
+            assert_eq!(
+                error.to_string(),
+                "invalid type: integer `123` as i128, expected a value of type `i64`, null, or unit",
+                "Failed to name expected inner type of adapter"
+            );
+        }
+
+        #[test]
+        fn with_value_outside_of_option() {
+            let deserializer = I64Deserializer::<serde::de::value::Error>::new(123);
+            let error = Possible::<i64>::deserialize(deserializer).unwrap_err();
+
+            assert_eq!(
+                error.to_string(),
+                "invalid type: integer `123`, expected a value of type `i64`, null, or unit",
+                "Failed to reject value that is not an option"
+            );
+        }
+    }
+
+    mod require {
+        use super::{FieldState, ParseTest};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_some_value() {
+            let parsed: ParseTest = serde_json::from_str(r#"{ "test": 123 }"#).unwrap();
+
+            assert_eq!(
+                parsed.test.require_field(),
+                Ok(123),
+                "Failed to return present value"
+            );
+        }
+
+        #[test]
+        fn with_null_value() {
+            let parsed: ParseTest = serde_json::from_str(r#"{ "test": null }"#).unwrap();
+            let error = parsed.test.require_field().unwrap_err();
+
+            assert_eq!(
+                error.state(),
+                FieldState::Null,
+                "Failed to report null field"
+            );
+            assert_eq!(
+                error.to_string(),
+                "null field: value must not be null",
+                "Failed to describe null field"
+            );
+        }
+
+        #[test]
+        fn with_no_field() {
+            let parsed: ParseTest = serde_json::from_str("{}").unwrap();
+            let error = parsed.test.require_field().unwrap_err();
+
+            assert_eq!(
+                error.state(),
+                FieldState::Missing,
+                "Failed to report missing field"
+            );
+            assert_eq!(
+                error.to_string(),
+                "missing field: value is required",
+                "Failed to describe missing field"
+            );
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Conformance tests running the examples from
+//! [appendix A of RFC 7396](https://tools.ietf.org/html/rfc7396#appendix-A).
+
+#![cfg(feature = "json-merge-patch")]
+
+use possible::{json_merge_patch, Possible};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+/// Each example as `(original, patch, result)`.
+fn examples() -> Vec<(Value, Value, Value)> {
+    vec![
+        (json!({"a": "b"}), json!({"a": "c"}), json!({"a": "c"})),
+        (
+            json!({"a": "b"}),
+            json!({"b": "c"}),
+            json!({"a": "b", "b": "c"}),
+        ),
+        (json!({"a": "b"}), json!({"a": null}), json!({})),
+        (
+            json!({"a": "b", "b": "c"}),
+            json!({"a": null}),
+            json!({"b": "c"}),
+        ),
+        (json!({"a": ["b"]}), json!({"a": "c"}), json!({"a": "c"})),
+        (json!({"a": "c"}), json!({"a": ["b"]}), json!({"a": ["b"]})),
+        (
+            json!({"a": {"b": "c"}}),
+            json!({"a": {"b": "d", "c": null}}),
+            json!({"a": {"b": "d"}}),
+        ),
+        (
+            json!({"a": [{"b": "c"}]}),
+            json!({"a": [1]}),
+            json!({"a": [1]}),
+        ),
+        (json!(["a", "b"]), json!(["c", "d"]), json!(["c", "d"])),
+        (json!({"a": "b"}), json!(["c"]), json!(["c"])),
+        (json!({"a": "foo"}), json!(null), json!(null)),
+        (json!({"a": "foo"}), json!("bar"), json!("bar")),
+        (
+            json!({"e": null}),
+            json!({"a": 1}),
+            json!({"e": null, "a": 1}),
+        ),
+        (
+            json!([1, 2]),
+            json!({"a": "b", "c": null}),
+            json!({"a": "b"}),
+        ),
+        (
+            json!({}),
+            json!({"a": {"bb": {"ccc": null}}}),
+            json!({"a": {"bb": {}}}),
+        ),
+    ]
+}
+
+/// A merge patch object parsed through the `Possible` serde implementations.
+type PatchObject = BTreeMap<String, Possible<Value>>;
+
+mod apply {
+    use super::{examples, json_merge_patch};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_rfc_examples() {
+        for (original, patch, result) in examples() {
+            let mut target = original.clone();
+            json_merge_patch::apply(&mut target, patch.clone());
+
+            assert_eq!(target, result, "Failed to apply {} to {}", patch, original);
+        }
+    }
+}
+
+mod diff {
+    use super::{examples, json_merge_patch};
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn with_rfc_examples() {
+        for (original, _, result) in examples() {
+            let patch = json_merge_patch::diff(&original, &result);
+
+            let mut target = original.clone();
+            json_merge_patch::apply(&mut target, patch.clone());
+
+            assert_eq!(
+                target, result,
+                "Failed to apply computed patch {} to {}",
+                patch, original
+            );
+        }
+    }
+
+    #[test]
+    fn with_equal_values() {
+        let value = json!({"a": {"b": [1, 2]}});
+
+        assert_eq!(json_merge_patch::diff(&value, &value), json!({}));
+    }
+}
+
+mod serde {
+    use super::{examples, json_merge_patch, PatchObject, Possible};
+    use pretty_assertions::assert_eq;
+    use serde_json::Value;
+
+    #[test]
+    fn with_rfc_examples() {
+        for (original, patch, result) in examples() {
+            if !patch.is_object() {
+                continue;
+            }
+
+            let parsed: PatchObject = serde_json::from_value(patch.clone()).unwrap();
+            for (name, member) in &parsed {
+                assert_eq!(
+                    member.is_none(),
+                    patch[name].is_null(),
+                    "Failed to parse member {} of {} as expected null",
+                    name,
+                    patch
+                );
+            }
+
+            let serialized = serde_json::to_value(&parsed).unwrap();
+            assert_eq!(
+                serialized, patch,
+                "Failed to serialize {} without changes",
+                patch
+            );
+
+            let mut target = original.clone();
+            json_merge_patch::apply(&mut target, serialized);
+            assert_eq!(target, result, "Failed to apply serialized {}", patch);
+        }
+    }
+
+    #[test]
+    fn with_null_member() {
+        let mut parsed = PatchObject::new();
+        parsed.insert(String::from("a"), Possible::None);
+        parsed.insert(String::from("b"), Possible::Some(Value::from(1)));
+
+        assert_eq!(
+            serde_json::to_string(&parsed).unwrap(),
+            r#"{"a":null,"b":1}"#,
+            "Failed to serialize expected null member"
+        );
+    }
+}
+
+mod typed {
+    use super::{json_merge_patch, Possible};
+    use pretty_assertions::assert_eq;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct Author {
+        #[serde(rename = "givenName")]
+        given_name: String,
+        #[serde(rename = "familyName")]
+        family_name: Option<String>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct Document {
+        title: String,
+        author: Author,
+        tags: Vec<String>,
+        phone: Option<String>,
+    }
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct AuthorPatch {
+        #[serde(rename = "givenName")]
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        given_name: Possible<String>,
+        #[serde(rename = "familyName")]
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        family_name: Possible<String>,
+    }
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct DocumentPatch {
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        title: Possible<String>,
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        author: Possible<AuthorPatch>,
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        tags: Possible<Vec<String>>,
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        phone: Possible<String>,
+    }
+
+    /// The example from section 3 of RFC 7396.
+    fn original() -> Document {
+        Document {
+            title: String::from("Goodbye!"),
+            author: Author {
+                given_name: String::from("John"),
+                family_name: Some(String::from("Doe")),
+            },
+            tags: vec![String::from("example"), String::from("sample")],
+            phone: None,
+        }
+    }
+
+    fn changed() -> Document {
+        Document {
+            title: String::from("Hello!"),
+            author: Author {
+                given_name: String::from("John"),
+                family_name: None,
+            },
+            tags: vec![String::from("example")],
+            phone: Some(String::from("+01-123-456-7890")),
+        }
+    }
+
+    #[test]
+    fn with_patch_struct() {
+        let patch = DocumentPatch {
+            title: Possible::Some(String::from("Hello!")),
+            author: Possible::Some(AuthorPatch {
+                given_name: Possible::Void,
+                family_name: Possible::None,
+            }),
+            tags: Possible::Some(vec![String::from("example")]),
+            phone: Possible::Some(String::from("+01-123-456-7890")),
+        };
+
+        let mut document = original();
+        json_merge_patch::apply_to(&mut document, &patch).unwrap();
+
+        assert_eq!(document, changed(), "Failed to apply patch struct");
+    }
+
+    #[test]
+    fn with_computed_patch_struct() {
+        let patch: DocumentPatch = json_merge_patch::diff_to(&original(), &changed()).unwrap();
+
+        assert_eq!(
+            patch,
+            DocumentPatch {
+                title: Possible::Some(String::from("Hello!")),
+                author: Possible::Some(AuthorPatch {
+                    given_name: Possible::Void,
+                    family_name: Possible::None,
+                }),
+                tags: Possible::Some(vec![String::from("example")]),
+                phone: Possible::Some(String::from("+01-123-456-7890")),
+            },
+            "Failed to compute expected patch struct"
+        );
+    }
+
+    #[test]
+    fn with_unchanged_values() {
+        let patch: DocumentPatch = json_merge_patch::diff_to(&original(), &original()).unwrap();
+
+        assert_eq!(
+            patch,
+            DocumentPatch::default(),
+            "Failed to compute empty patch struct"
+        );
+    }
+}
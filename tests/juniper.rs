@@ -0,0 +1,213 @@
+//! Tests for `Possible` arguments, input fields, and results with an in-process juniper
+//! schema.
+
+#![cfg(feature = "juniper")]
+
+use juniper::{
+    graphql_object, graphql_value, EmptySubscription, GraphQLInputObject, GraphQLObject, Nullable,
+    RootNode, Variables,
+};
+use possible::Possible;
+use std::sync::Mutex;
+
+#[derive(Clone, Debug, PartialEq, GraphQLObject)]
+#[graphql(context = Context)]
+pub struct User {
+    name: String,
+    email: Option<String>,
+    age: Possible<i32>,
+}
+
+#[derive(GraphQLInputObject)]
+pub struct UserInput {
+    name: Possible<String>,
+    email: Possible<String>,
+}
+
+pub struct Context {
+    user: Mutex<User>,
+}
+
+impl juniper::Context for Context {}
+
+pub struct Query;
+
+#[graphql_object(context = Context)]
+impl Query {
+    fn user(context: &Context) -> User {
+        context.user.lock().unwrap().clone()
+    }
+
+    fn state(value: Possible<i32>) -> String {
+        match value {
+            Possible::Some(value) => format!("some {}", value),
+            Possible::None => String::from("none"),
+            Possible::Void => String::from("void"),
+        }
+    }
+
+    fn echo(value: Possible<i32>) -> Possible<i32> {
+        value
+    }
+}
+
+pub struct Mutation;
+
+#[graphql_object(context = Context)]
+impl Mutation {
+    fn update_user(context: &Context, input: UserInput) -> User {
+        let mut user = context.user.lock().unwrap();
+
+        match input.name {
+            Possible::Some(name) => user.name = name,
+            Possible::None | Possible::Void => {}
+        }
+        match input.email {
+            Possible::Some(email) => user.email = Some(email),
+            Possible::None => user.email = None,
+            Possible::Void => {}
+        }
+
+        user.clone()
+    }
+}
+
+fn execute(query: &str) -> juniper::Value {
+    let schema = RootNode::new(Query, Mutation, EmptySubscription::<Context>::new());
+    let context = Context {
+        user: Mutex::new(User {
+            name: String::from("Ferris"),
+            email: Some(String::from("ferris@example.com")),
+            age: Possible::Some(7),
+        }),
+    };
+
+    let (value, errors) =
+        juniper::execute_sync(query, None, &schema, &Variables::new(), &context).unwrap();
+
+    assert!(errors.is_empty(), "{:?}", errors);
+    value
+}
+
+mod input {
+    use super::{execute, graphql_value};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_all_states() {
+        let data = execute(
+            r#"{
+                some: state(value: 7)
+                none: state(value: null)
+                void: state
+            }"#,
+        );
+
+        assert_eq!(
+            data,
+            graphql_value!({ "some": "some 7", "none": "none", "void": "void" }),
+            "Failed to parse expected argument states"
+        );
+    }
+
+    #[test]
+    fn with_null_mutation() {
+        let data = execute(r#"mutation { updateUser(input: { email: null }) { name email } }"#);
+
+        assert_eq!(
+            data,
+            graphql_value!({ "updateUser": { "name": "Ferris", "email": null } }),
+            "Failed to clear null input field"
+        );
+    }
+
+    #[test]
+    fn with_some_mutation() {
+        let data = execute(
+            r#"mutation { updateUser(input: { name: "Corro", email: "corro@example.com" }) { name email } }"#,
+        );
+
+        assert_eq!(
+            data,
+            graphql_value!({ "updateUser": { "name": "Corro", "email": "corro@example.com" } }),
+            "Failed to set present input fields"
+        );
+    }
+
+    #[test]
+    fn with_void_mutation() {
+        let data = execute(r#"mutation { updateUser(input: {}) { name email } }"#);
+
+        assert_eq!(
+            data,
+            graphql_value!({ "updateUser": { "name": "Ferris", "email": "ferris@example.com" } }),
+            "Failed to leave missing input fields unchanged"
+        );
+    }
+}
+
+mod output {
+    use super::{execute, graphql_value};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_all_states() {
+        let data = execute(
+            r#"{
+                user { age }
+                some: echo(value: 7)
+                none: echo(value: null)
+                void: echo
+            }"#,
+        );
+
+        assert_eq!(
+            data,
+            graphql_value!({ "user": { "age": 7 }, "some": 7, "none": null, "void": null }),
+            "Failed to resolve expected values"
+        );
+    }
+
+    #[test]
+    fn with_nullable_type() {
+        let data = execute(r#"{ __type(name: "User") { fields { name type { kind } } } }"#);
+
+        assert_eq!(
+            data,
+            graphql_value!({ "__type": { "fields": [
+                { "name": "name", "type": { "kind": "NON_NULL" } },
+                { "name": "email", "type": { "kind": "SCALAR" } },
+                { "name": "age", "type": { "kind": "SCALAR" } },
+            ] } }),
+            "Failed to declare possible field as nullable"
+        );
+    }
+}
+
+mod conversion {
+    use super::{Nullable, Possible};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_all_states() {
+        let values = [
+            Nullable::Some(7),
+            Nullable::ExplicitNull,
+            Nullable::ImplicitNull,
+        ];
+        let possible: Vec<Possible<i32>> = values.iter().cloned().map(Possible::from).collect();
+
+        assert_eq!(
+            possible,
+            [Possible::Some(7), Possible::None, Possible::Void],
+            "Failed to convert from nullable values"
+        );
+
+        let round_trip: Vec<Nullable<i32>> = possible.into_iter().map(Nullable::from).collect();
+
+        assert_eq!(
+            round_trip, values,
+            "Failed to convert back to nullable values"
+        );
+    }
+}
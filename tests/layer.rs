@@ -0,0 +1,159 @@
+//! Tests for reading configuration layers from `figment` providers and `config` sources.
+
+#![cfg(all(feature = "derive", any(feature = "figment", feature = "config")))]
+
+use possible::{
+    layer::{Layered, Source},
+    Possible,
+};
+use serde::{Deserialize, Serialize};
+
+#[possible::fields]
+#[derive(Debug, Default, Serialize, Deserialize, Layered)]
+#[layered(name = "Config", derive(Debug, PartialEq))]
+pub struct ConfigLayer {
+    #[layered(nested)]
+    server: Possible<ServerLayer>,
+    proxy: Possible<String>,
+}
+
+#[possible::fields]
+#[derive(Debug, Default, Serialize, Deserialize, Layered)]
+#[layered(name = "Server", derive(Debug, PartialEq))]
+pub struct ServerLayer {
+    #[layered(required)]
+    host: Possible<String>,
+    #[layered(required)]
+    port: Possible<u16>,
+}
+
+const FILE: &str = r#"
+proxy = "http://proxy"
+
+[server]
+host = "localhost"
+port = 8080
+"#;
+
+fn set(layer: &str) -> Option<Source> {
+    Some(Source::Set(String::from(layer)))
+}
+
+fn cleared(layer: &str) -> Option<Source> {
+    Some(Source::Cleared(String::from(layer)))
+}
+
+#[cfg(feature = "figment")]
+mod with_figment {
+    use super::{cleared, set, Config, ConfigLayer, Possible, Server, ServerLayer, FILE};
+    use figment::providers::{Format, Serialized, Toml};
+    use possible::layer::Stack;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_providers() {
+        let cli = ConfigLayer {
+            server: Possible::Some(ServerLayer {
+                host: Possible::Void,
+                port: Possible::Some(80),
+            }),
+            proxy: Possible::None,
+        };
+        let resolved = Stack::<ConfigLayer>::new()
+            .push_provider("file", Toml::string(FILE))
+            .expect("Failed to read file layer")
+            .push_provider("cli", Serialized::defaults(cli))
+            .expect("Failed to read cli layer")
+            .resolve()
+            .expect("Failed to resolve layers");
+
+        assert_eq!(
+            resolved.value,
+            Config {
+                server: Server {
+                    host: String::from("localhost"),
+                    port: 80,
+                },
+                proxy: None,
+            },
+            "Failed to resolve provider layers"
+        );
+        assert_eq!(
+            [
+                resolved.provenance.get("server.host").cloned(),
+                resolved.provenance.get("server.port").cloned(),
+                resolved.provenance.get("proxy").cloned(),
+            ],
+            [set("file"), set("cli"), cleared("cli")],
+            "Failed to record provider layers"
+        );
+    }
+
+    #[test]
+    fn with_invalid_provider() {
+        let result = Stack::<ConfigLayer>::new().push_provider("file", Toml::string("proxy = 1"));
+
+        assert!(result.is_err(), "Failed to reject invalid provider");
+    }
+}
+
+#[cfg(feature = "config")]
+mod with_config {
+    use super::{cleared, set, Config, ConfigLayer, Server, FILE};
+    use config::{Environment, File, FileFormat};
+    use possible::layer::Stack;
+    use pretty_assertions::assert_eq;
+    use std::collections::HashMap;
+
+    #[test]
+    fn with_sources() {
+        let env = HashMap::from([(String::from("APP_SERVER__PORT"), String::from("80"))]);
+        let resolved = Stack::<ConfigLayer>::new()
+            .push_source("file", File::from_str(FILE, FileFormat::Toml))
+            .expect("Failed to read file layer")
+            .push_source(
+                "env",
+                Environment::with_prefix("APP")
+                    .prefix_separator("_")
+                    .separator("__")
+                    .source(Some(env)),
+            )
+            .expect("Failed to read env layer")
+            .push_source(
+                "overrides",
+                File::from_str(r#"{ "proxy": null }"#, FileFormat::Json),
+            )
+            .expect("Failed to read overrides layer")
+            .resolve()
+            .expect("Failed to resolve layers");
+
+        assert_eq!(
+            resolved.value,
+            Config {
+                server: Server {
+                    host: String::from("localhost"),
+                    port: 80,
+                },
+                proxy: None,
+            },
+            "Failed to resolve source layers"
+        );
+        assert_eq!(
+            [
+                resolved.provenance.get("server.host").cloned(),
+                resolved.provenance.get("server.port").cloned(),
+                resolved.provenance.get("proxy").cloned(),
+            ],
+            [set("file"), set("env"), cleared("overrides")],
+            "Failed to record source layers"
+        );
+    }
+
+    #[test]
+    fn with_invalid_source() {
+        let result = Stack::<ConfigLayer>::new()
+            .push_source("file", File::from_str("proxy = [1]", FileFormat::Toml));
+
+        assert!(result.is_err(), "Failed to reject invalid source");
+    }
+}
@@ -0,0 +1,254 @@
+//! Property tests for the combinators of `Possible`, with values generated by `proptest`.
+
+#![cfg(feature = "proptest")]
+
+use possible::{Possible, Weights};
+
+// the `Option` model of a `Possible`, where `Void` and `None` are both `None`
+fn option<T>(value: Possible<T>) -> Option<T> {
+    value.into()
+}
+
+// the states of a `Possible`, which `Option` cannot tell apart between `None` and `Void`
+fn state<T>(value: &Possible<T>) -> Possible<()> {
+    value.as_ref().map(|_| ())
+}
+
+mod strategy {
+    use super::{Possible, Weights};
+    use pretty_assertions::assert_eq;
+    use proptest::{
+        prelude::*,
+        strategy::ValueTree,
+        test_runner::{TestError, TestRunner},
+    };
+
+    fn minimal<S>(strategy: S, fails: fn(&S::Value) -> bool) -> S::Value
+    where
+        S: Strategy,
+        S::Value: Clone,
+    {
+        let mut runner = TestRunner::deterministic();
+        match runner.run(&strategy, |value| {
+            prop_assert!(!fails(&value));
+            Ok(())
+        }) {
+            Err(TestError::Fail(_, value)) => value,
+            result => panic!("Expected a failing value, found {:?}", result),
+        }
+    }
+
+    #[test]
+    fn with_weights() {
+        let strategy = possible::strategy(Weights::new(0, 1, 1), any::<u8>());
+        let mut runner = TestRunner::deterministic();
+        let mut states = Vec::new();
+        for _ in 0..100 {
+            states.push(super::state(
+                &strategy.new_tree(&mut runner).unwrap().current(),
+            ));
+        }
+
+        assert!(
+            !states.contains(&Possible::Some(())),
+            "Failed to leave out state without weight"
+        );
+        assert!(
+            states.contains(&Possible::None) && states.contains(&Possible::Void),
+            "Failed to generate every state with weight"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one `Possible` weight must not be zero")]
+    fn without_weights() {
+        let strategy = possible::strategy(Weights::new(0, 0, 0), any::<u8>());
+        let _ = strategy.new_tree(&mut TestRunner::deterministic());
+    }
+
+    #[test]
+    fn with_shrinking_to_void() {
+        assert_eq!(
+            minimal(any::<Possible<u8>>(), |_| true),
+            Possible::Void,
+            "Failed to shrink toward void"
+        );
+    }
+
+    #[test]
+    fn with_shrinking_to_none() {
+        let strategy = possible::strategy(Weights::new(1, 1, 0), any::<u8>());
+
+        assert_eq!(
+            minimal(strategy, |_| true),
+            Possible::None,
+            "Failed to shrink toward none without void weight"
+        );
+    }
+
+    #[test]
+    fn with_shrinking_inner_value() {
+        assert_eq!(
+            minimal(any::<Possible<u8>>(), |value| {
+                matches!(value, Possible::Some(value) if *value >= 10)
+            }),
+            Possible::Some(10),
+            "Failed to shrink inner value"
+        );
+    }
+
+    #[test]
+    fn with_arbitrary_parameters() {
+        let strategy = any_with::<Possible<u8>>((Weights::new(0, 0, 1), ()));
+        let mut runner = TestRunner::deterministic();
+
+        assert_eq!(
+            strategy.new_tree(&mut runner).unwrap().current(),
+            Possible::Void,
+            "Failed to use weights of parameters"
+        );
+    }
+}
+
+mod boolean {
+    use super::{option, state, Possible};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn and_matches_option(a in any::<Possible<u8>>(), b in any::<Possible<i8>>()) {
+            prop_assert_eq!(option(a.and(b)), option(a).and(option(b)));
+        }
+
+        #[test]
+        fn and_keeps_state(a in any::<Possible<u8>>(), b in any::<Possible<i8>>()) {
+            let expected = if a.is_some() { state(&b) } else { state(&a) };
+            prop_assert_eq!(state(&a.and(b)), expected);
+        }
+
+        #[test]
+        fn and_is_associative(
+            a in any::<Possible<u8>>(),
+            b in any::<Possible<u8>>(),
+            c in any::<Possible<u8>>(),
+        ) {
+            prop_assert_eq!(a.and(b).and(c), a.and(b.and(c)));
+        }
+
+        #[test]
+        fn and_then_matches_and(a in any::<Possible<u8>>(), b in any::<Possible<i8>>()) {
+            prop_assert_eq!(a.and_then(|_| b), a.and(b));
+        }
+
+        #[test]
+        fn and_then_some_matches_map(a in any::<Possible<u8>>()) {
+            prop_assert_eq!(a.and_then(|x| Possible::Some(x / 2)), a.map(|x| x / 2));
+        }
+
+        #[test]
+        fn or_matches_option(a in any::<Possible<u8>>(), b in any::<Possible<u8>>()) {
+            prop_assert_eq!(option(a.or(b)), option(a).or(option(b)));
+        }
+
+        #[test]
+        fn or_keeps_state(a in any::<Possible<u8>>(), b in any::<Possible<u8>>()) {
+            let expected = if a.is_some() { state(&a) } else { state(&b) };
+            prop_assert_eq!(state(&a.or(b)), expected);
+        }
+
+        #[test]
+        fn or_is_associative(
+            a in any::<Possible<u8>>(),
+            b in any::<Possible<u8>>(),
+            c in any::<Possible<u8>>(),
+        ) {
+            prop_assert_eq!(a.or(b).or(c), a.or(b.or(c)));
+        }
+
+        #[test]
+        fn or_else_matches_or(a in any::<Possible<u8>>(), b in any::<Possible<u8>>()) {
+            prop_assert_eq!(a.or_else(|| b), a.or(b));
+        }
+    }
+}
+
+mod map {
+    use super::{option, state, Possible};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn map_with_identity(a in any::<Possible<u8>>()) {
+            prop_assert_eq!(a.map(|x| x), a);
+        }
+
+        #[test]
+        fn map_composes(a in any::<Possible<u8>>()) {
+            let f = |x: u8| u16::from(x) + 1;
+            let g = |x: u16| x * 3;
+
+            prop_assert_eq!(a.map(f).map(g), a.map(|x| g(f(x))));
+        }
+
+        #[test]
+        fn map_keeps_state(a in any::<Possible<u8>>()) {
+            prop_assert_eq!(state(&a.map(|x| x.to_string())), state(&a));
+        }
+
+        #[test]
+        fn map_or_matches_option(a in any::<Possible<u8>>(), default in any::<u16>()) {
+            prop_assert_eq!(
+                a.map_or(default, u16::from),
+                option(a).map_or(default, u16::from)
+            );
+        }
+
+        #[test]
+        fn map_or_else_matches_map_or(a in any::<Possible<u8>>(), default in any::<u16>()) {
+            prop_assert_eq!(a.map_or_else(|| default, u16::from), a.map_or(default, u16::from));
+        }
+
+        #[test]
+        fn ok_or_matches_option(a in any::<Possible<u8>>(), error in any::<i8>()) {
+            prop_assert_eq!(a.ok_or(error), option(a).ok_or(error));
+        }
+
+        #[test]
+        fn ok_or_else_matches_ok_or(a in any::<Possible<u8>>(), error in any::<i8>()) {
+            prop_assert_eq!(a.ok_or_else(|| error), a.ok_or(error));
+        }
+    }
+}
+
+mod zip {
+    use super::{option, state, Possible};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn zip_matches_option(a in any::<Possible<u8>>(), b in any::<Possible<i8>>()) {
+            prop_assert_eq!(option(a.zip(b)), option(a).zip(option(b)));
+        }
+
+        #[test]
+        fn zip_keeps_state(a in any::<Possible<u8>>(), b in any::<Possible<i8>>()) {
+            let expected = match (state(&a), state(&b)) {
+                (Possible::Some(()), Possible::Some(())) => Possible::Some(()),
+                (Possible::Void, _) | (_, Possible::Void) => Possible::Void,
+                _ => Possible::None,
+            };
+
+            prop_assert_eq!(state(&a.zip(b)), expected);
+        }
+
+        #[test]
+        fn zip_is_symmetric(a in any::<Possible<u8>>(), b in any::<Possible<i8>>()) {
+            prop_assert_eq!(a.zip(b).map(|(x, y)| (y, x)), b.zip(a));
+        }
+
+        #[test]
+        fn zip_with_some_matches_map(a in any::<Possible<u8>>(), b in any::<i8>()) {
+            prop_assert_eq!(a.zip(Possible::Some(b)), a.map(|x| (x, b)));
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Property tests for the combinators of `Possible`, with values generated by `quickcheck`.
+
+#![cfg(feature = "quickcheck")]
+
+use possible::Possible;
+use quickcheck::{quickcheck, Arbitrary, Gen};
+
+// the `Option` model of a `Possible`, where `Void` and `None` are both `None`
+fn option<T>(value: Possible<T>) -> Option<T> {
+    value.into()
+}
+
+mod arbitrary {
+    use super::{Arbitrary, Gen, Possible};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_every_state() {
+        let mut generator = Gen::new(10);
+        let values: Vec<Possible<u8>> = (0..100)
+            .map(|_| Possible::arbitrary(&mut generator))
+            .collect();
+
+        assert!(
+            values.iter().any(Possible::is_some)
+                && values.iter().any(Possible::is_none)
+                && values.iter().any(Possible::is_void),
+            "Failed to generate every state"
+        );
+    }
+
+    #[test]
+    fn with_shrinking_some() {
+        let shrunk: Vec<_> = Possible::Some(4u8).shrink().collect();
+
+        assert_eq!(
+            shrunk[..3],
+            [Possible::Void, Possible::None, Possible::Some(0)],
+            "Failed to shrink toward void before inner value"
+        );
+    }
+
+    #[test]
+    fn with_shrinking_none() {
+        assert_eq!(
+            Possible::<u8>::None.shrink().collect::<Vec<_>>(),
+            [Possible::Void],
+            "Failed to shrink none toward void"
+        );
+    }
+
+    #[test]
+    fn with_shrinking_void() {
+        assert_eq!(
+            Possible::<u8>::Void.shrink().count(),
+            0,
+            "Failed to keep void as simplest value"
+        );
+    }
+}
+
+quickcheck! {
+    fn and_matches_option(a: Possible<u8>, b: Possible<i8>) -> bool {
+        option(a.and(b)) == option(a).and(option(b))
+    }
+
+    fn or_matches_option(a: Possible<u8>, b: Possible<u8>) -> bool {
+        option(a.or(b)) == option(a).or(option(b))
+    }
+
+    fn map_with_identity(a: Possible<u8>) -> bool {
+        a.map(|x| x) == a
+    }
+
+    fn zip_is_symmetric(a: Possible<u8>, b: Possible<i8>) -> bool {
+        a.zip(b).map(|(x, y)| (y, x)) == b.zip(a)
+    }
+}
@@ -0,0 +1,102 @@
+//! Snapshot tests for the JSON schemas generated for `Possible` fields.
+
+#![cfg(feature = "schemars")]
+
+mod with_possible {
+    use possible::Possible;
+    use schemars::{schema_for, JsonSchema};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+    pub struct ParseTest {
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        test: Possible<i64>,
+    }
+
+    // like other fields, `Possible` fields are only left out of `required` with a default
+    #[derive(Serialize, JsonSchema)]
+    pub struct PatchTest {
+        id: u64,
+        name: Possible<String>,
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        tags: Possible<Vec<String>>,
+        #[schemars(default, skip_serializing_if = "Possible::is_void")]
+        nested: Possible<ParseTest>,
+    }
+
+    mod schema {
+        use super::{json, schema_for, ParseTest, PatchTest};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_possible_field() {
+            let schema = serde_json::to_value(schema_for!(ParseTest)).unwrap();
+
+            assert_eq!(
+                schema,
+                json!({
+                    "$schema": "https://json-schema.org/draft/2020-12/schema",
+                    "title": "ParseTest",
+                    "type": "object",
+                    "properties": {
+                        "test": {
+                            "type": ["integer", "null"],
+                            "format": "int64"
+                        }
+                    }
+                }),
+                "Failed to generate expected schema"
+            );
+        }
+
+        #[test]
+        fn with_required_and_nested_fields() {
+            let schema = serde_json::to_value(schema_for!(PatchTest)).unwrap();
+
+            assert_eq!(
+                schema,
+                json!({
+                    "$schema": "https://json-schema.org/draft/2020-12/schema",
+                    "title": "PatchTest",
+                    "type": "object",
+                    "properties": {
+                        "id": {
+                            "type": "integer",
+                            "format": "uint64",
+                            "minimum": 0
+                        },
+                        "name": {
+                            "type": ["string", "null"]
+                        },
+                        "tags": {
+                            "type": ["array", "null"],
+                            "items": {
+                                "type": "string"
+                            }
+                        },
+                        "nested": {
+                            "anyOf": [
+                                { "$ref": "#/$defs/ParseTest" },
+                                { "type": "null" }
+                            ]
+                        }
+                    },
+                    "required": ["id", "name"],
+                    "$defs": {
+                        "ParseTest": {
+                            "type": "object",
+                            "properties": {
+                                "test": {
+                                    "type": ["integer", "null"],
+                                    "format": "int64"
+                                }
+                            }
+                        }
+                    }
+                }),
+                "Failed to generate expected schema"
+            );
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Tests for converting and patching SeaORM `ActiveValue`s with `Possible` values.
+
+#![cfg(feature = "sea-orm")]
+
+use possible::{Patch, PatchError, Possible};
+use sea_orm::ActiveValue;
+
+mod conversion {
+    use super::{ActiveValue, Possible};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn into_active_value() {
+        let values: [ActiveValue<Option<i32>>; 3] = [
+            Possible::Some(7).into(),
+            Possible::None.into(),
+            Possible::Void.into(),
+        ];
+
+        assert_eq!(
+            values,
+            [
+                ActiveValue::Set(Some(7)),
+                ActiveValue::Set(None),
+                ActiveValue::NotSet
+            ],
+            "Failed to convert expected active values"
+        );
+    }
+
+    #[test]
+    fn from_active_value() {
+        let values: [Possible<i32>; 4] = [
+            ActiveValue::Set(Some(7)).into(),
+            ActiveValue::Set(None).into(),
+            ActiveValue::Unchanged(Some(8)).into(),
+            ActiveValue::NotSet.into(),
+        ];
+
+        assert_eq!(
+            values,
+            [
+                Possible::Some(7),
+                Possible::None,
+                Possible::Some(8),
+                Possible::Void
+            ],
+            "Failed to convert expected possible values"
+        );
+    }
+}
+
+mod patch {
+    use super::{ActiveValue, Patch, PatchError, Possible};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_nullable_target() {
+        let mut target = ActiveValue::Unchanged(Some(7));
+        Possible::<i32>::Void.apply(&mut target).unwrap();
+        assert_eq!(
+            target,
+            ActiveValue::Unchanged(Some(7)),
+            "Failed to leave target unchanged"
+        );
+
+        Possible::<i32>::None.apply(&mut target).unwrap();
+        assert_eq!(target, ActiveValue::Set(None), "Failed to set null value");
+    }
+
+    #[test]
+    fn with_non_nullable_target() {
+        let mut target = ActiveValue::Unchanged(7);
+        Possible::Some(8).apply(&mut target).unwrap();
+        assert_eq!(target, ActiveValue::Set(8), "Failed to set value");
+
+        assert_eq!(
+            Possible::<i32>::None.apply(&mut target),
+            Err(PatchError::NotNullable),
+            "Failed to reject null for non-nullable target"
+        );
+    }
+}
+
+#[cfg(feature = "derive")]
+mod active_model {
+    use super::{ActiveValue, Patch, PatchError, Possible};
+    use pretty_assertions::assert_eq;
+
+    mod user {
+        use sea_orm::entity::prelude::*;
+        // used by the code generated for the 2021 edition prelude
+        #[allow(unused_imports)]
+        use std::convert::TryInto;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel, possible::Patch)]
+        #[sea_orm(table_name = "users")]
+        #[patch(name = "UserPatch", active_model = "ActiveModel")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            #[patch(skip)]
+            pub id: i32,
+            pub name: String,
+            pub email: Option<String>,
+            pub age: Option<i32>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    use user::{ActiveModel, UserPatch};
+
+    fn ferris() -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Unchanged(1),
+            name: ActiveValue::Unchanged(String::from("Ferris")),
+            email: ActiveValue::Unchanged(Some(String::from("ferris@example.com"))),
+            age: ActiveValue::Unchanged(Some(7)),
+        }
+    }
+
+    #[test]
+    fn with_all_states() {
+        let mut model = ferris();
+        let patch = UserPatch {
+            name: Possible::Some(String::from("Corro")),
+            email: Possible::None,
+            age: Possible::Void,
+        };
+        patch.apply(&mut model).unwrap();
+
+        assert_eq!(
+            model,
+            ActiveModel {
+                name: ActiveValue::Set(String::from("Corro")),
+                email: ActiveValue::Set(None),
+                ..ferris()
+            },
+            "Failed to set only present fields"
+        );
+    }
+
+    #[test]
+    fn with_null_non_nullable_field() {
+        let mut model = ferris();
+        let patch = UserPatch {
+            name: Possible::None,
+            ..UserPatch::default()
+        };
+
+        assert_eq!(
+            patch.apply(&mut model),
+            Err(PatchError::field("name", PatchError::NotNullable)),
+            "Failed to reject null for non-nullable field"
+        );
+    }
+}
@@ -0,0 +1,447 @@
+//! Round-trip tests for the alternative representations in `possible::repr`, each using
+//! a format that loses information with the default representation.
+
+#![cfg(feature = "serde")]
+
+#[cfg(feature = "alloc")]
+mod as_tagged {
+    use possible::Possible;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct ParseTest {
+        #[serde(with = "possible::repr::as_tagged")]
+        test: Possible<i64>,
+    }
+
+    mod serialization {
+        use super::{ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_some_value() {
+            let data = ParseTest {
+                test: Possible::Some(123),
+            };
+            let serialized = toml::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, "[test]\nstate = \"some\"\nvalue = 123\n",
+                "Failed to serialize expected number value"
+            );
+        }
+
+        #[test]
+        fn with_null_value() {
+            let data = ParseTest {
+                test: Possible::None,
+            };
+            let serialized = toml::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, "[test]\nstate = \"none\"\n",
+                "Failed to serialize expected null value"
+            );
+        }
+
+        #[test]
+        fn with_no_field() {
+            let data = ParseTest {
+                test: Possible::Void,
+            };
+            let serialized = toml::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, "[test]\nstate = \"void\"\n",
+                "Failed to serialize expected field omission"
+            );
+        }
+    }
+
+    mod round_trip {
+        use super::{ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_every_state() {
+            for test in [Possible::Some(123), Possible::None, Possible::Void] {
+                let data = ParseTest { test };
+                let serialized = toml::to_string(&data).unwrap();
+                let parsed: ParseTest = toml::from_str(&serialized).unwrap();
+
+                assert_eq!(parsed, data, "Failed to round-trip {}", serialized);
+            }
+        }
+    }
+}
+
+mod as_sentinel {
+    use possible::Possible;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct ParseTest {
+        #[serde(with = "possible::repr::as_sentinel")]
+        test: Possible<i64>,
+    }
+
+    mod serialization {
+        use super::{ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_some_value() {
+            let data = ParseTest {
+                test: Possible::Some(123),
+            };
+            let serialized = serde_yaml::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, "---\ntest: 123\n",
+                "Failed to serialize expected number value"
+            );
+        }
+
+        #[test]
+        fn with_null_value() {
+            let data = ParseTest {
+                test: Possible::None,
+            };
+            let serialized = serde_yaml::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, "---\ntest: ~\n",
+                "Failed to serialize expected null value"
+            );
+        }
+
+        #[test]
+        fn with_no_field() {
+            let data = ParseTest {
+                test: Possible::Void,
+            };
+            let serialized = serde_yaml::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, "---\ntest: __undefined__\n",
+                "Failed to serialize expected sentinel"
+            );
+        }
+
+        #[test]
+        fn with_sentinel_value() {
+            #[derive(Debug, Serialize, Deserialize, PartialEq)]
+            pub struct StringTest {
+                #[serde(with = "possible::repr::as_sentinel")]
+                test: Possible<String>,
+            }
+            use serde::{Deserialize, Serialize};
+
+            let data = StringTest {
+                test: Possible::Some(String::from("__undefined__")),
+            };
+
+            assert!(
+                serde_json::to_string(&data).is_err(),
+                "Failed to reject value reserved for sentinel"
+            );
+            assert!(
+                serde_yaml::to_string(&data).is_err(),
+                "Failed to reject value reserved for sentinel"
+            );
+        }
+    }
+
+    mod round_trip {
+        use super::{ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_every_state() {
+            for test in [Possible::Some(123), Possible::None, Possible::Void] {
+                let data = ParseTest { test };
+
+                let serialized = serde_yaml::to_string(&data).unwrap();
+                let parsed: ParseTest = serde_yaml::from_str(&serialized).unwrap();
+                assert_eq!(parsed, data, "Failed to round-trip {}", serialized);
+
+                let serialized = serde_json::to_string(&data).unwrap();
+                let parsed: ParseTest = serde_json::from_str(&serialized).unwrap();
+                assert_eq!(parsed, data, "Failed to round-trip {}", serialized);
+            }
+        }
+    }
+
+    mod deserialization {
+        use super::{ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_invalid_value() {
+            let json = r#"{ "test": "123" }"#;
+            let parsed = serde_json::from_str::<ParseTest>(json);
+
+            assert!(parsed.is_err(), "Failed to reject invalid value");
+        }
+
+        #[test]
+        fn with_sentinel_string() {
+            #[derive(Debug, Serialize, Deserialize, PartialEq)]
+            pub struct StringTest {
+                #[serde(with = "possible::repr::as_sentinel")]
+                test: Possible<String>,
+            }
+            use serde::{Deserialize, Serialize};
+
+            let json = r#"{ "test": "__undefined__" }"#;
+            let parsed: StringTest = serde_json::from_str(json).unwrap();
+
+            assert_eq!(
+                parsed,
+                StringTest {
+                    test: Possible::Void
+                },
+                "Failed to parse expected sentinel"
+            );
+        }
+    }
+}
+
+mod as_null_void {
+    use possible::Possible;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct ParseTest {
+        #[serde(default, with = "possible::repr::as_null_void")]
+        test: Possible<i64>,
+    }
+
+    mod serialization {
+        use super::{ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_some_value() {
+            let data = ParseTest {
+                test: Possible::Some(123),
+            };
+            let serialized = serde_json::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, r#"{"test":123}"#,
+                "Failed to serialize expected number value"
+            );
+        }
+
+        #[test]
+        fn with_null_value() {
+            let data = ParseTest {
+                test: Possible::None,
+            };
+            let serialized = serde_json::to_string(&data);
+
+            assert!(serialized.is_err(), "Failed to reject null value");
+        }
+
+        #[test]
+        fn with_no_field() {
+            let data = ParseTest {
+                test: Possible::Void,
+            };
+            let serialized = serde_json::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, r#"{"test":null}"#,
+                "Failed to serialize expected field omission"
+            );
+        }
+    }
+
+    mod deserialization {
+        use super::{ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_null_value() {
+            let json = r#"{ "test": null }"#;
+            let parsed: ParseTest = serde_json::from_str(json).unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest {
+                    test: Possible::Void,
+                },
+                "Failed to parse null as void"
+            );
+        }
+
+        #[test]
+        fn with_no_field() {
+            let json = r#"{ }"#;
+            let parsed: ParseTest = serde_json::from_str(json).unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest {
+                    test: Possible::Void,
+                },
+                "Failed to parse expected field omission"
+            );
+        }
+    }
+}
+
+mod as_empty_none {
+    use possible::Possible;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct ParseTest {
+        #[serde(default, with = "possible::repr::as_empty_none")]
+        #[serde(skip_serializing_if = "Possible::is_void")]
+        test: Possible<String>,
+    }
+
+    mod serialization {
+        use super::{ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_some_value() {
+            let data = ParseTest {
+                test: Possible::Some(String::from("abc")),
+            };
+            let serialized = serde_qs::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, "test=abc",
+                "Failed to serialize expected string value"
+            );
+        }
+
+        #[test]
+        fn with_null_value() {
+            let data = ParseTest {
+                test: Possible::None,
+            };
+            let serialized = serde_qs::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, "test=",
+                "Failed to serialize expected null value"
+            );
+        }
+
+        #[test]
+        fn with_no_field() {
+            let data = ParseTest {
+                test: Possible::Void,
+            };
+            let serialized = serde_qs::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, "",
+                "Failed to serialize expected field omission"
+            );
+        }
+    }
+
+    mod round_trip {
+        use super::{ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_every_state() {
+            for test in [
+                Possible::Some(String::from("abc")),
+                Possible::None,
+                Possible::Void,
+            ] {
+                let data = ParseTest { test };
+
+                let serialized = serde_qs::to_string(&data).unwrap();
+                let parsed: ParseTest = serde_qs::from_str(&serialized).unwrap();
+                assert_eq!(parsed, data, "Failed to round-trip {}", serialized);
+
+                let serialized = serde_json::to_string(&data).unwrap();
+                let parsed: ParseTest = serde_json::from_str(&serialized).unwrap();
+                assert_eq!(parsed, data, "Failed to round-trip {}", serialized);
+            }
+        }
+    }
+}
+
+mod as_deny_void {
+    use possible::Possible;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct ParseTest {
+        #[serde(with = "possible::repr::as_deny_void")]
+        test: Possible<i64>,
+    }
+
+    mod serialization {
+        use super::{ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_null_value() {
+            let data = ParseTest {
+                test: Possible::None,
+            };
+            let serialized = ron::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, "(test:None)",
+                "Failed to serialize expected null value"
+            );
+        }
+
+        #[test]
+        fn with_no_field() {
+            let data = ParseTest {
+                test: Possible::Void,
+            };
+            let serialized = ron::to_string(&data);
+
+            assert!(serialized.is_err(), "Failed to reject field omission");
+        }
+    }
+
+    mod deserialization {
+        use super::{ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_some_value() {
+            let ron = r#"(test:Some(123))"#;
+            let parsed: ParseTest = ron::from_str(ron).unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest {
+                    test: Possible::Some(123),
+                },
+                "Failed to parse expected number value"
+            );
+        }
+
+        #[test]
+        fn with_unit_value() {
+            let ron = r#"(test:())"#;
+            let parsed = ron::from_str::<ParseTest>(ron);
+
+            assert!(parsed.is_err(), "Failed to reject unit value");
+        }
+
+        #[test]
+        fn with_no_field() {
+            let ron = r#"()"#;
+            let parsed = ron::from_str::<ParseTest>(ron);
+
+            assert!(parsed.is_err(), "Failed to reject field omission");
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Tests for parsing and writing `Possible` with `serde-json-core`, as embedded firmware
+//! would. The test harness itself links `std`, so the `no_std` build of the library is
+//! checked separately by building it for a target without the standard library.
+
+#![cfg(feature = "serde")]
+
+mod with_possible {
+    use possible::Possible;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct ParseTest<'a> {
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Possible::is_void")]
+        test: Possible<i64>,
+        #[serde(default, borrow)]
+        #[serde(skip_serializing_if = "Possible::is_void")]
+        name: Possible<&'a str>,
+    }
+
+    mod serialization {
+        use super::{ParseTest, Possible};
+
+        #[test]
+        fn with_all_states() {
+            let data = ParseTest {
+                test: Possible::Some(123),
+                name: Possible::Void,
+            };
+            let mut buffer = [0; 32];
+            let length = serde_json_core::to_slice(&data, &mut buffer).unwrap();
+
+            assert_eq!(
+                &buffer[..length],
+                br#"{"test":123}"#,
+                "Failed to serialize expected value"
+            );
+
+            let data = ParseTest {
+                test: Possible::None,
+                name: Possible::Some("Ferris"),
+            };
+            let length = serde_json_core::to_slice(&data, &mut buffer).unwrap();
+
+            assert_eq!(
+                &buffer[..length],
+                br#"{"test":null,"name":"Ferris"}"#,
+                "Failed to serialize expected null value"
+            );
+        }
+    }
+
+    mod deserialization {
+        use super::{ParseTest, Possible};
+
+        #[test]
+        fn with_some_value() {
+            let json = r#"{ "test": 123, "name": "Ferris" }"#;
+            let (parsed, _): (ParseTest, _) = serde_json_core::from_str(json).unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest {
+                    test: Possible::Some(123),
+                    name: Possible::Some("Ferris"),
+                },
+                "Failed to parse expected values"
+            );
+        }
+
+        #[test]
+        fn with_null_value() {
+            let json = r#"{ "test": null }"#;
+            let (parsed, _): (ParseTest, _) = serde_json_core::from_str(json).unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest {
+                    test: Possible::None,
+                    name: Possible::Void,
+                },
+                "Failed to parse expected null value"
+            );
+        }
+
+        #[test]
+        fn with_no_field() {
+            let json = r#"{}"#;
+            let (parsed, _): (ParseTest, _) = serde_json_core::from_str(json).unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest::default(),
+                "Failed to parse expected field omission"
+            );
+        }
+    }
+}
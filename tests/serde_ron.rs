@@ -1,3 +1,5 @@
+#![cfg(feature = "serde")]
+
 mod with_possible {
     use possible::*;
     use serde::{Deserialize, Serialize};
@@ -1,6 +1,11 @@
 //! While there is valid test coverage for the TOML format, `Possible` behaves exactly
 //! like `Option` due to TOML not supporting an explicit null value type to differentiate
 //! between an explicit null and the absense of a value.
+//!
+//! The `toml` feature adds strategies that represent an explicit null as a sentinel string
+//! or a marker table, which are covered by the `with_sentinel` and `with_marker` modules.
+
+#![cfg(feature = "serde")]
 
 mod with_possible {
     use possible::*;
@@ -154,3 +159,325 @@ mod baseline_with_option {
         }
     }
 }
+
+#[cfg(feature = "toml")]
+mod with_sentinel {
+    use possible::Possible;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct Server {
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        #[serde(with = "possible::repr::toml::sentinel")]
+        host: Possible<String>,
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        #[serde(with = "possible::repr::toml::sentinel")]
+        port: Possible<u16>,
+    }
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct ParseTest {
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        #[serde(with = "possible::repr::toml::sentinel")]
+        test: Possible<i64>,
+        #[serde(default, with = "possible::repr::toml::sentinel::seq")]
+        list: Vec<Possible<i64>>,
+        #[serde(default)]
+        server: Server,
+    }
+
+    mod serialization {
+        use super::{ParseTest, Possible, Server};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_null_value() {
+            let data = ParseTest {
+                test: Possible::None,
+                ..ParseTest::default()
+            };
+            let serialized = toml::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, "test = \"__null__\"\nlist = []\n\n[server]\n",
+                "Failed to serialize expected sentinel"
+            );
+        }
+
+        #[test]
+        fn with_nested_struct_and_array() {
+            let data = ParseTest {
+                test: Possible::Void,
+                list: vec![Possible::Some(1), Possible::None, Possible::Some(3)],
+                server: Server {
+                    host: Possible::None,
+                    port: Possible::Some(8080),
+                },
+            };
+            let serialized = toml::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized,
+                "list = [1, \"__null__\", 3]\n\n[server]\nhost = \"__null__\"\nport = 8080\n",
+                "Failed to serialize expected nested values"
+            );
+        }
+
+        #[test]
+        fn with_void_array_element() {
+            let data = ParseTest {
+                list: vec![Possible::Some(1), Possible::Void],
+                ..ParseTest::default()
+            };
+            let serialized = toml::to_string(&data);
+
+            assert!(serialized.is_err(), "Failed to reject void array element");
+        }
+
+        #[test]
+        fn with_sentinel_value() {
+            let data = ParseTest {
+                server: Server {
+                    host: Possible::Some(String::from("__null__")),
+                    port: Possible::Void,
+                },
+                ..ParseTest::default()
+            };
+            let serialized = toml::to_string(&data);
+
+            assert!(serialized.is_err(), "Failed to reject ambiguous value");
+        }
+    }
+
+    mod deserialization {
+        use super::{ParseTest, Possible, Server};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_inline_table() {
+            let toml = r#"
+                test = "__null__"
+                list = ["__null__", 2]
+                server = { host = "localhost", port = "__null__" }
+            "#;
+            let parsed: ParseTest = toml::from_str(toml).unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest {
+                    test: Possible::None,
+                    list: vec![Possible::None, Possible::Some(2)],
+                    server: Server {
+                        host: Possible::Some(String::from("localhost")),
+                        port: Possible::None,
+                    },
+                },
+                "Failed to parse expected inline table"
+            );
+        }
+
+        #[test]
+        fn with_invalid_value() {
+            let toml = r#"test = "123""#;
+            let parsed = toml::from_str::<ParseTest>(toml);
+
+            assert!(parsed.is_err(), "Failed to reject invalid value");
+        }
+    }
+
+    mod round_trip {
+        use super::{ParseTest, Possible, Server};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_every_state() {
+            for test in [Possible::Some(123), Possible::None, Possible::Void] {
+                let data = ParseTest {
+                    test,
+                    list: vec![Possible::None, Possible::Some(2)],
+                    server: Server {
+                        host: Possible::Some(String::from("localhost")),
+                        port: Possible::None,
+                    },
+                };
+                let serialized = toml::to_string(&data).unwrap();
+                let parsed: ParseTest = toml::from_str(&serialized).unwrap();
+
+                assert_eq!(parsed, data, "Failed to round-trip {}", serialized);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+mod with_marker {
+    use possible::Possible;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct Server {
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        #[serde(with = "possible::repr::toml::marker")]
+        host: Possible<String>,
+    }
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct ParseTest {
+        #[serde(default, with = "possible::repr::toml::marker::seq")]
+        list: Vec<Possible<i64>>,
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        #[serde(with = "possible::repr::toml::marker")]
+        test: Possible<i64>,
+        #[serde(default)]
+        server: Server,
+    }
+
+    mod serialization {
+        use super::{ParseTest, Possible, Server};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_null_value() {
+            let data = ParseTest {
+                test: Possible::None,
+                ..ParseTest::default()
+            };
+            let serialized = toml::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, "list = []\n\n[test]\nnull = true\n\n[server]\n",
+                "Failed to serialize expected marker"
+            );
+        }
+
+        #[test]
+        fn with_nested_struct_and_array() {
+            let data = ParseTest {
+                list: vec![Possible::Some(1), Possible::None],
+                test: Possible::Some(123),
+                server: Server {
+                    host: Possible::None,
+                },
+            };
+            let serialized = toml::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized,
+                "list = [1, { null = true }]\ntest = 123\n\n[server.host]\nnull = true\n",
+                "Failed to serialize expected nested values"
+            );
+        }
+
+        #[test]
+        fn with_void_array_element() {
+            let data = ParseTest {
+                list: vec![Possible::Void],
+                ..ParseTest::default()
+            };
+            let serialized = toml::to_string(&data);
+
+            assert!(serialized.is_err(), "Failed to reject void array element");
+        }
+
+        #[test]
+        fn with_no_field() {
+            #[derive(Serialize)]
+            pub struct UnskippedTest {
+                #[serde(with = "possible::repr::toml::marker")]
+                test: Possible<i64>,
+            }
+            use serde::Serialize;
+
+            let data = UnskippedTest {
+                test: Possible::Void,
+            };
+            let serialized = toml::to_string(&data);
+
+            assert!(serialized.is_err(), "Failed to reject unskipped void field");
+        }
+    }
+
+    mod deserialization {
+        use super::{ParseTest, Possible, Server};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_inline_table() {
+            let toml = r#"
+                list = [{ null = true }, 2]
+                test = { null = true }
+                server = { host = { null = true } }
+            "#;
+            let parsed: ParseTest = toml::from_str(toml).unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest {
+                    list: vec![Possible::None, Possible::Some(2)],
+                    test: Possible::None,
+                    server: Server {
+                        host: Possible::None,
+                    },
+                },
+                "Failed to parse expected inline table"
+            );
+        }
+
+        #[test]
+        fn with_ambiguous_marker() {
+            for toml in [
+                "test = { null = false }",
+                "test = { null = true, value = 1 }",
+                "list = [{ null = 1 }]",
+            ] {
+                let parsed = toml::from_str::<ParseTest>(toml);
+
+                assert!(parsed.is_err(), "Failed to reject ambiguous {}", toml);
+            }
+        }
+
+        #[test]
+        fn with_ambiguous_value() {
+            use possible::Possible;
+            use serde::{Deserialize, Serialize};
+            use std::collections::BTreeMap;
+
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct MapTest {
+                #[serde(with = "possible::repr::toml::marker")]
+                test: Possible<BTreeMap<String, bool>>,
+            }
+
+            let mut map = BTreeMap::new();
+            map.insert(String::from("null"), true);
+            let data = MapTest {
+                test: Possible::Some(map),
+            };
+            let serialized = toml::to_string(&data);
+
+            assert!(serialized.is_err(), "Failed to reject ambiguous value");
+        }
+    }
+
+    mod round_trip {
+        use super::{ParseTest, Possible, Server};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_every_state() {
+            for test in [Possible::Some(123), Possible::None, Possible::Void] {
+                let data = ParseTest {
+                    list: vec![Possible::None, Possible::Some(2)],
+                    test,
+                    server: Server {
+                        host: Possible::Some(String::from("localhost")),
+                    },
+                };
+                let serialized = toml::to_string(&data).unwrap();
+                let parsed: ParseTest = toml::from_str(&serialized).unwrap();
+
+                assert_eq!(parsed, data, "Failed to round-trip {}", serialized);
+            }
+        }
+    }
+}
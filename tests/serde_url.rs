@@ -1,6 +1,9 @@
 //! While there is valid test coverage for the [URL query string format](https://url.spec.whatwg.org/#urlencoded-parsing),
-//! `Possible` behaves exactly like `Option` due to TOML not supporting an explicit null value
-//! type to differentiate between an explicit null and the absense of a value.
+//! `Possible` behaves exactly like `Option` due to query strings not supporting an explicit
+//! null value type to differentiate between an explicit null and the absense of a value.
+//! The `possible::repr::as_query` adapter represents an explicit null as an empty value.
+
+#![cfg(feature = "serde")]
 
 mod with_possible {
     use possible::*;
@@ -178,3 +181,255 @@ mod baseline_with_option {
         }
     }
 }
+
+#[cfg(feature = "alloc")]
+mod with_as_query {
+    use possible::Possible;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct ParseTest {
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        #[serde(with = "possible::repr::as_query")]
+        test: Possible<i64>,
+
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        #[serde(with = "possible::repr::as_query::seq")]
+        list: Possible<Vec<i64>>,
+    }
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct NullLiteralTest {
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        #[serde(
+            serialize_with = "possible::repr::as_query::serialize_with::<possible::repr::as_query::NullLiteral, _, _>",
+            deserialize_with = "possible::repr::as_query::deserialize_with::<possible::repr::as_query::NullLiteral, _, _>"
+        )]
+        test: Possible<String>,
+    }
+
+    mod serialization {
+        use super::{NullLiteralTest, ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_all_states() {
+            let data = ParseTest {
+                test: Possible::None,
+                list: Possible::Some(vec![1, 2]),
+            };
+            let serialized = serde_qs::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, "test=&list[0]=1&list[1]=2",
+                "Failed to serialize expected query"
+            );
+
+            let data = ParseTest {
+                test: Possible::Some(123),
+                list: Possible::None,
+            };
+            let serialized = serde_qs::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, "test=123&list=",
+                "Failed to serialize expected query"
+            );
+        }
+
+        #[test]
+        fn with_ambiguous_values() {
+            let data = ParseTest {
+                list: Possible::Some(vec![]),
+                ..ParseTest::default()
+            };
+            assert!(
+                serde_qs::to_string(&data).is_err(),
+                "Failed to reject empty sequence"
+            );
+
+            let data = NullLiteralTest {
+                test: Possible::Some(String::from("null")),
+            };
+            assert!(
+                serde_qs::to_string(&data).is_err(),
+                "Failed to reject null literal"
+            );
+        }
+    }
+
+    mod deserialization {
+        use super::{NullLiteralTest, ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_all_states() {
+            let parsed: ParseTest = serde_qs::from_str("test=&list[]=1&list[]=2").unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest {
+                    test: Possible::None,
+                    list: Possible::Some(vec![1, 2]),
+                },
+                "Failed to parse expected query"
+            );
+
+            let parsed: ParseTest = serde_qs::from_str("test=123&list=").unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest {
+                    test: Possible::Some(123),
+                    list: Possible::None,
+                },
+                "Failed to parse expected query"
+            );
+        }
+
+        #[test]
+        fn with_urlencoded() {
+            let parsed: ParseTest = serde_urlencoded::from_str("test=&list=7").unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest {
+                    test: Possible::None,
+                    list: Possible::Some(vec![7]),
+                },
+                "Failed to parse expected form"
+            );
+
+            let parsed: ParseTest = serde_urlencoded::from_str("").unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest::default(),
+                "Failed to parse expected field omission"
+            );
+        }
+
+        #[test]
+        fn with_null_literal() {
+            let parsed: [NullLiteralTest; 3] = [
+                serde_qs::from_str("test=null").unwrap(),
+                serde_qs::from_str("test=").unwrap(),
+                serde_qs::from_str("test=nil").unwrap(),
+            ];
+
+            assert_eq!(
+                parsed,
+                [
+                    NullLiteralTest {
+                        test: Possible::None
+                    },
+                    NullLiteralTest {
+                        test: Possible::None
+                    },
+                    NullLiteralTest {
+                        test: Possible::Some(String::from("nil"))
+                    },
+                ],
+                "Failed to parse expected null literal"
+            );
+        }
+    }
+}
+
+#[cfg(feature = "serde_urlencoded")]
+mod with_urlencoded {
+    use possible::{repr::urlencoded, Possible};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct ParseTest {
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        #[serde(with = "possible::repr::as_query")]
+        test: Possible<i64>,
+
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        #[serde(with = "possible::repr::as_query::seq")]
+        list: Possible<Vec<String>>,
+    }
+
+    mod round_trip {
+        use super::{urlencoded, ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_repeated_keys() {
+            let data = ParseTest {
+                test: Possible::None,
+                list: Possible::Some(vec![String::from("a b"), String::from("c")]),
+            };
+            let serialized = urlencoded::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, "test=&list=a+b&list=c",
+                "Failed to serialize expected form"
+            );
+            assert_eq!(
+                urlencoded::from_str::<ParseTest>(&serialized).unwrap(),
+                data,
+                "Failed to parse serialized form"
+            );
+        }
+
+        #[test]
+        fn with_void_fields() {
+            let serialized = urlencoded::to_string(&ParseTest::default()).unwrap();
+
+            assert_eq!(serialized, "", "Failed to serialize expected form");
+            assert_eq!(
+                urlencoded::from_str::<ParseTest>(&serialized).unwrap(),
+                ParseTest::default(),
+                "Failed to parse serialized form"
+            );
+        }
+    }
+
+    mod deserialization {
+        use super::{urlencoded, ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_bracketed_keys() {
+            let parsed: ParseTest = urlencoded::from_str("list[]=a&test=5").unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest {
+                    test: Possible::Some(5),
+                    list: Possible::Some(vec![String::from("a")]),
+                },
+                "Failed to parse expected form"
+            );
+        }
+
+        #[test]
+        fn with_interleaved_keys() {
+            let parsed: ParseTest = urlencoded::from_str("list=a&test=5&list[]=b&list=c").unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest {
+                    test: Possible::Some(5),
+                    list: Possible::Some(vec![
+                        String::from("a"),
+                        String::from("b"),
+                        String::from("c")
+                    ]),
+                },
+                "Failed to group values of interleaved keys in order"
+            );
+        }
+
+        #[test]
+        fn with_repeated_scalar_key() {
+            assert!(
+                urlencoded::from_str::<ParseTest>("test=1&test=2").is_err(),
+                "Failed to reject repeated scalar key"
+            );
+        }
+    }
+}
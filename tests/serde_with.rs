@@ -0,0 +1,126 @@
+//! Tests for combining `Possible` with `serde_with` adapters through `PossibleAs`.
+
+#![cfg(feature = "serde_with")]
+
+mod with_possible {
+    use possible::{Possible, PossibleAs};
+    use serde::{Deserialize, Serialize};
+    use serde_with::{base64::Base64, serde_as, DisplayFromStr, DurationSeconds};
+    use std::time::Duration;
+
+    #[serde_as]
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+    pub struct ParseTest {
+        #[serde_as(as = "PossibleAs<DisplayFromStr>")]
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        id: Possible<u64>,
+        #[serde_as(as = "PossibleAs<DurationSeconds<u64>>")]
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        timeout: Possible<Duration>,
+        #[serde_as(as = "PossibleAs<Base64>")]
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        data: Possible<Vec<u8>>,
+        #[serde_as(as = "PossibleAs<Vec<DisplayFromStr>>")]
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        tags: Possible<Vec<u32>>,
+    }
+
+    mod serialization {
+        use super::{Duration, ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_some_values() {
+            let data = ParseTest {
+                id: Possible::Some(1324),
+                timeout: Possible::Some(Duration::from_secs(30)),
+                data: Possible::Some(b"possible".to_vec()),
+                tags: Possible::Some(vec![1, 2]),
+            };
+            let serialized = serde_json::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, r#"{"id":"1324","timeout":30,"data":"cG9zc2libGU=","tags":["1","2"]}"#,
+                "Failed to serialize expected converted values"
+            );
+        }
+
+        #[test]
+        fn with_null_values() {
+            let data = ParseTest {
+                id: Possible::None,
+                timeout: Possible::None,
+                data: Possible::None,
+                tags: Possible::None,
+            };
+            let serialized = serde_json::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, r#"{"id":null,"timeout":null,"data":null,"tags":null}"#,
+                "Failed to serialize expected null values"
+            );
+        }
+
+        #[test]
+        fn with_no_fields() {
+            let data = ParseTest::default();
+            let serialized = serde_json::to_string(&data).unwrap();
+
+            assert_eq!(
+                serialized, r#"{}"#,
+                "Failed to serialize expected field omission"
+            );
+        }
+    }
+
+    mod deserialization {
+        use super::{ParseTest, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_mixed_states() {
+            let json = r#"{ "id": "1324", "timeout": null, "data": "cG9zc2libGU=" }"#;
+            let parsed: ParseTest = serde_json::from_str(json).unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest {
+                    id: Possible::Some(1324),
+                    timeout: Possible::None,
+                    data: Possible::Some(b"possible".to_vec()),
+                    tags: Possible::Void,
+                },
+                "Failed to parse expected converted values"
+            );
+        }
+
+        #[test]
+        fn with_invalid_value() {
+            let json = r#"{ "id": 1324 }"#;
+            let parsed = serde_json::from_str::<ParseTest>(json);
+
+            assert!(parsed.is_err(), "Failed to reject unconverted value");
+        }
+
+        #[test]
+        fn with_ron_states() {
+            let ron = r#"(id: Some("7"), timeout: None, tags: Some(["3"]))"#;
+            let parsed: ParseTest = ron::from_str(ron).unwrap();
+
+            assert_eq!(
+                parsed,
+                ParseTest {
+                    id: Possible::Some(7),
+                    timeout: Possible::None,
+                    data: Possible::Void,
+                    tags: Possible::Some(vec![3]),
+                },
+                "Failed to parse expected RON values"
+            );
+
+            let serialized = ron::to_string(&parsed).unwrap();
+            let reparsed: ParseTest = ron::from_str(&serialized).unwrap();
+            assert_eq!(reparsed, parsed, "Failed to round-trip {}", serialized);
+        }
+    }
+}
@@ -0,0 +1,253 @@
+//! Tests for binding and reading `Possible` values with an in-memory SQLite database.
+
+#![cfg(feature = "sqlx")]
+
+use possible::{sql::Columns, Possible};
+use sqlx::{sqlite::SqlitePoolOptions, FromRow, QueryBuilder, Sqlite, SqlitePool};
+
+#[derive(Debug, FromRow, PartialEq)]
+pub struct User {
+    id: i64,
+    name: String,
+    email: Possible<String>,
+    age: Possible<i64>,
+}
+
+async fn connect() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "CREATE TABLE users (
+            id INTEGER PRIMARY KEY,
+            name TEXT NOT NULL DEFAULT 'anonymous',
+            email TEXT,
+            age INTEGER DEFAULT 7
+        )",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+async fn insert(pool: &SqlitePool, columns: Columns<'_, Sqlite>) -> User {
+    let mut query = QueryBuilder::new("INSERT INTO users ");
+    columns.push_insert(&mut query);
+    query.push(" RETURNING *");
+
+    query.build_query_as().fetch_one(pool).await.unwrap()
+}
+
+fn run<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
+
+mod encoding {
+    use super::{connect, run, Possible};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_some_value() {
+        run(async {
+            let pool = connect().await;
+            let value: Option<String> = sqlx::query_scalar("SELECT ?")
+                .bind(Possible::Some("Ferris"))
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                value,
+                Some(String::from("Ferris")),
+                "Failed to bind expected value"
+            );
+        });
+    }
+
+    #[test]
+    fn with_null_value() {
+        run(async {
+            let pool = connect().await;
+            let value: Option<String> = sqlx::query_scalar("SELECT ?")
+                .bind(Possible::<&str>::None)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+
+            assert_eq!(value, None, "Failed to bind null value");
+        });
+    }
+
+    #[test]
+    fn with_void_value() {
+        run(async {
+            let pool = connect().await;
+            let result = sqlx::query_scalar::<_, Option<String>>("SELECT ?")
+                .bind(Possible::<&str>::Void)
+                .fetch_one(&pool)
+                .await;
+
+            assert!(result.is_err(), "Failed to reject void value");
+        });
+    }
+}
+
+mod decoding {
+    use super::{connect, run, Possible};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_some_value() {
+        run(async {
+            let pool = connect().await;
+            let value: Possible<i64> = sqlx::query_scalar("SELECT 42")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+
+            assert_eq!(value, Possible::Some(42), "Failed to read expected value");
+        });
+    }
+
+    #[test]
+    fn with_null_value() {
+        run(async {
+            let pool = connect().await;
+            let value: Possible<i64> = sqlx::query_scalar("SELECT NULL")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+
+            assert_eq!(value, Possible::None, "Failed to read null value");
+        });
+    }
+}
+
+mod columns {
+    use super::{connect, insert, run, Columns, Possible, User};
+    use pretty_assertions::assert_eq;
+    use sqlx::{QueryBuilder, Sqlite};
+
+    #[test]
+    fn with_insert() {
+        run(async {
+            let pool = connect().await;
+            let mut columns = Columns::new();
+            columns
+                .push("name", Possible::Some("Ferris"))
+                .push("email", Possible::<&str>::None)
+                .push("age", Possible::<i64>::Void);
+            let user = insert(&pool, columns).await;
+
+            assert_eq!(
+                user,
+                User {
+                    id: 1,
+                    name: String::from("Ferris"),
+                    email: Possible::None,
+                    age: Possible::Some(7),
+                },
+                "Failed to apply default for void column"
+            );
+        });
+    }
+
+    #[test]
+    fn with_insert_of_no_columns() {
+        run(async {
+            let pool = connect().await;
+            let mut columns = Columns::new();
+            columns.push("name", Possible::<&str>::Void);
+            let user = insert(&pool, columns).await;
+
+            assert_eq!(
+                user,
+                User {
+                    id: 1,
+                    name: String::from("anonymous"),
+                    email: Possible::None,
+                    age: Possible::Some(7),
+                },
+                "Failed to apply defaults for all columns"
+            );
+        });
+    }
+
+    #[test]
+    fn with_update() {
+        run(async {
+            let pool = connect().await;
+            let mut columns = Columns::new();
+            columns
+                .push("name", Possible::Some("Ferris"))
+                .push("email", Possible::Some("ferris@example.com"));
+            insert(&pool, columns).await;
+
+            let mut columns = Columns::<Sqlite>::new();
+            columns
+                .push("name", Possible::<&str>::Void)
+                .push("email", Possible::<&str>::None)
+                .push("age", Possible::Some(8));
+
+            let mut query = columns
+                .push_assignments(QueryBuilder::new("UPDATE users SET "))
+                .expect("Failed to write assignments");
+            query.push(" WHERE id = ").push_bind(1);
+            query.push(" RETURNING *");
+
+            assert_eq!(
+                query.sql(),
+                "UPDATE users SET email = ?, age = ? WHERE id = ? RETURNING *",
+                "Failed to leave out void column"
+            );
+
+            let user: User = query.build_query_as().fetch_one(&pool).await.unwrap();
+
+            assert_eq!(
+                user,
+                User {
+                    id: 1,
+                    name: String::from("Ferris"),
+                    email: Possible::None,
+                    age: Possible::Some(8),
+                },
+                "Failed to update only present columns"
+            );
+        });
+    }
+
+    #[test]
+    fn with_only_void_columns() {
+        let mut columns = Columns::<Sqlite>::new();
+        columns
+            .push("name", Possible::<&str>::Void)
+            .push("age", Possible::<i64>::Void);
+
+        assert!(columns.is_empty(), "Failed to leave out void columns");
+        assert_eq!(columns.len(), 0);
+    }
+
+    #[test]
+    fn with_update_of_only_void_columns() {
+        let mut columns = Columns::<Sqlite>::new();
+        columns
+            .push("name", Possible::<&str>::Void)
+            .push("age", Possible::<i64>::Void);
+
+        assert!(
+            columns
+                .push_assignments(QueryBuilder::new("UPDATE users SET "))
+                .is_none(),
+            "Failed to refuse update without assignments"
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! Snapshot tests for the OpenAPI schemas and parameters generated for `Possible` fields.
+
+#![cfg(feature = "utoipa")]
+
+mod with_possible {
+    use possible::Possible;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use utoipa::{openapi::ComponentsBuilder, IntoParams, PartialSchema, ToSchema};
+
+    // the derive only composes generic field types through a hidden trait, so `Possible`
+    // fields take their schema from `PartialSchema` with `schema_with`
+    #[derive(Debug, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+    pub struct ParseTest {
+        id: u64,
+
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        #[schema(schema_with = <Possible<String> as PartialSchema>::schema)]
+        name: Possible<String>,
+
+        #[serde(default, skip_serializing_if = "Possible::is_void")]
+        #[schema(schema_with = <Possible<Vec<String>> as PartialSchema>::schema)]
+        tags: Possible<Vec<String>>,
+    }
+
+    // without `serde(default)`, utoipa lists a `Possible` field as required, even though
+    // serde reads a missing field as `Possible::None`
+    #[derive(Debug, Serialize, Deserialize, PartialEq, ToSchema)]
+    pub struct BareTest {
+        #[schema(schema_with = <Possible<i64> as PartialSchema>::schema)]
+        test: Possible<i64>,
+
+        #[schema(schema_with = <Possible<i64> as PartialSchema>::schema, required = false)]
+        optional: Possible<i64>,
+    }
+
+    #[derive(Debug, Deserialize, IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(dead_code)] // only the generated parameters are tested
+    pub struct QueryTest {
+        page: u32,
+
+        #[serde(default)]
+        #[param(schema_with = <Possible<u32> as PartialSchema>::schema)]
+        limit: Possible<u32>,
+    }
+
+    mod schema {
+        use super::{json, BareTest, ComponentsBuilder, ParseTest, PartialSchema, Possible};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_possible_fields() {
+            let schema = serde_json::to_value(ParseTest::schema()).unwrap();
+
+            assert_eq!(
+                schema,
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "id": {
+                            "type": "integer",
+                            "format": "int64",
+                            "minimum": 0
+                        },
+                        "name": {
+                            "type": ["string", "null"]
+                        },
+                        "tags": {
+                            "type": ["array", "null"],
+                            "items": {
+                                "type": "string"
+                            }
+                        }
+                    },
+                    "required": ["id"]
+                }),
+                "Failed to generate expected schema"
+            );
+        }
+
+        #[test]
+        fn with_bare_fields() {
+            let schema = serde_json::to_value(BareTest::schema()).unwrap();
+
+            assert_eq!(
+                schema,
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "test": {
+                            "type": ["integer", "null"],
+                            "format": "int64"
+                        },
+                        "optional": {
+                            "type": ["integer", "null"],
+                            "format": "int64"
+                        }
+                    },
+                    "required": ["test"]
+                }),
+                "Failed to generate expected schema"
+            );
+        }
+
+        #[test]
+        fn with_components() {
+            let components = ComponentsBuilder::new()
+                .schema_from::<Possible<i64>>()
+                .schema_from::<Possible<String>>()
+                .build();
+            let components = serde_json::to_value(components).unwrap();
+
+            assert_eq!(
+                components,
+                json!({
+                    "schemas": {
+                        "Possible_String": {
+                            "type": ["string", "null"]
+                        },
+                        "Possible_i64": {
+                            "type": ["integer", "null"],
+                            "format": "int64"
+                        }
+                    }
+                }),
+                "Failed to name components after their inner type"
+            );
+        }
+    }
+
+    mod params {
+        use super::{json, IntoParams, QueryTest};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn with_possible_field() {
+            let params = serde_json::to_value(QueryTest::into_params(|| None)).unwrap();
+
+            assert_eq!(
+                params,
+                json!([
+                    {
+                        "name": "page",
+                        "in": "query",
+                        "required": true,
+                        "schema": {
+                            "type": "integer",
+                            "format": "int32",
+                            "minimum": 0
+                        }
+                    },
+                    {
+                        "name": "limit",
+                        "in": "query",
+                        "required": false,
+                        "schema": {
+                            "type": ["integer", "null"],
+                            "format": "int32",
+                            "minimum": 0
+                        }
+                    }
+                ]),
+                "Failed to generate expected parameters"
+            );
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! Tests for validating `Possible` fields with `validator`.
+
+#![cfg(feature = "validator")]
+
+use possible::Possible;
+use validator::Validate;
+
+#[derive(Debug, Validate)]
+pub struct UserPatch {
+    #[validate(
+        custom(function = "possible::validate::not_null"),
+        length(min = 1, max = 50)
+    )]
+    name: Possible<String>,
+
+    #[validate(custom(function = "possible::validate::not_void"), email)]
+    email: Possible<String>,
+
+    #[validate(required, range(min = 0, max = 150))]
+    age: Possible<u8>,
+
+    #[validate(nested)]
+    address: Possible<Address>,
+}
+
+#[derive(Debug, Validate)]
+pub struct Address {
+    #[validate(custom(function = "possible::validate::required"))]
+    city: Possible<String>,
+}
+
+fn ferris() -> UserPatch {
+    UserPatch {
+        name: Possible::Some(String::from("Ferris")),
+        email: Possible::None,
+        age: Possible::Some(7),
+        address: Possible::Void,
+    }
+}
+
+mod rules {
+    use super::{ferris, Possible, UserPatch, Validate};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_valid_states() {
+        assert_eq!(ferris().validate(), Ok(()), "Failed to accept valid states");
+
+        let patch = UserPatch {
+            name: Possible::Void,
+            ..ferris()
+        };
+        assert_eq!(patch.validate(), Ok(()), "Failed to accept void name");
+    }
+
+    #[test]
+    fn with_invalid_states() {
+        let patch = UserPatch {
+            name: Possible::None,
+            email: Possible::Void,
+            age: Possible::None,
+            ..ferris()
+        };
+        let errors = patch.validate().unwrap_err();
+        let errors = errors.field_errors();
+
+        assert_eq!(
+            [
+                errors["name"][0].code.as_ref(),
+                errors["email"][0].code.as_ref(),
+                errors["age"][0].code.as_ref(),
+            ],
+            ["not_null", "not_void", "required"],
+            "Failed to reject invalid states"
+        );
+        assert_eq!(
+            [
+                &errors["name"][0].params["state"],
+                &errors["email"][0].params["state"],
+            ],
+            ["null", "missing"],
+            "Failed to report field states"
+        );
+    }
+
+    #[test]
+    fn with_invalid_values() {
+        let patch = UserPatch {
+            name: Possible::Some(String::new()),
+            email: Possible::Some(String::from("ferris")),
+            age: Possible::Some(200),
+            ..ferris()
+        };
+        let errors = patch.validate().unwrap_err();
+        let errors = errors.field_errors();
+
+        assert_eq!(
+            [
+                errors["name"][0].code.as_ref(),
+                errors["email"][0].code.as_ref(),
+                errors["age"][0].code.as_ref(),
+            ],
+            ["length", "email", "range"],
+            "Failed to reject invalid values"
+        );
+    }
+}
+
+mod nested {
+    use super::{ferris, Address, Possible, UserPatch, Validate};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn with_present_value() {
+        let patch = UserPatch {
+            address: Possible::Some(Address {
+                city: Possible::None,
+            }),
+            ..ferris()
+        };
+        let errors = patch.validate().unwrap_err();
+
+        assert!(
+            errors.errors().contains_key("address"),
+            "Failed to validate nested value"
+        );
+    }
+
+    #[test]
+    fn with_null_value() {
+        let patch = UserPatch {
+            address: Possible::None,
+            ..ferris()
+        };
+
+        assert_eq!(patch.validate(), Ok(()), "Failed to skip null value");
+    }
+}